@@ -0,0 +1,131 @@
+use std::time::{Duration, Instant};
+use tool_versions::{
+    ast::{Identifier, Line, Node, Version, Versions, Whitespace, AST},
+    document::Document,
+    parser,
+};
+
+fn input(tools: usize) -> String {
+    (0..tools)
+        .map(|i| format!("tool-{}  {}.0.0   system # generated\n", i, i))
+        .collect()
+}
+
+fn baseline_set_versions(ast: &AST, tool_name: Identifier, versions: Vec<Version>) -> AST {
+    if !ast
+        .lines
+        .iter()
+        .any(|line| matches!(line, Line::ToolDefinition { name, .. } if *name == tool_name))
+    {
+        let mut lines = ast.lines.clone();
+
+        if !versions.is_empty() {
+            lines.push(Line::ToolDefinition {
+                name: tool_name,
+                versions: Versions::new(
+                    versions
+                        .into_iter()
+                        .map(|version| (Whitespace::new(" ".to_string()), version))
+                        .collect(),
+                ),
+                whitespace: None,
+                comment: None,
+            });
+        }
+
+        return AST { lines };
+    }
+
+    AST {
+        lines: ast
+            .lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::ToolDefinition {
+                    name,
+                    whitespace,
+                    comment,
+                    versions: old_versions,
+                } if *name == tool_name => {
+                    if versions.is_empty() {
+                        return None;
+                    }
+
+                    let new_versions = versions
+                        .iter()
+                        .enumerate()
+                        .map(|(i, version)| {
+                            let left_padding = match old_versions.value().get(i) {
+                                Some((old_padding, _)) => old_padding.clone(),
+                                None => Whitespace::new(" ".to_string()),
+                            };
+
+                            (left_padding, version.clone())
+                        })
+                        .collect();
+
+                    Some(Line::ToolDefinition {
+                        name: name.clone(),
+                        versions: Versions::new(new_versions),
+                        whitespace: whitespace.clone(),
+                        comment: comment.clone(),
+                    })
+                }
+                _ => Some(line.clone()),
+            })
+            .collect(),
+    }
+}
+
+fn measure<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn main() {
+    for tools in [100, 500, 2_000] {
+        let input = input(tools);
+        let edits: Vec<(Identifier, Vec<Version>)> = (0..tools)
+            .map(|i| {
+                let versions = match i % 10 {
+                    0 => vec![],
+                    _ => vec![Version::new(format!("{}.1.0", i))],
+                };
+
+                (Identifier::new(format!("tool-{}", i)), versions)
+            })
+            .collect();
+
+        let mut baseline_ast = None;
+        let baseline = measure(|| {
+            let mut ast = parser::parse(&input);
+
+            for (tool_name, versions) in &edits {
+                ast = baseline_set_versions(&ast, tool_name.clone(), versions.clone());
+            }
+
+            baseline_ast = Some(ast);
+        });
+
+        let mut indexed_ast = None;
+        let indexed = measure(|| {
+            let mut document = Document::new(parser::parse(&input));
+
+            for (tool_name, versions) in &edits {
+                document
+                    .set_versions(tool_name.clone(), versions.clone())
+                    .unwrap();
+            }
+
+            indexed_ast = Some(document.into_ast());
+        });
+
+        assert_eq!(baseline_ast, indexed_ast);
+
+        println!(
+            "{:>5} tools: baseline {:>10.2?}  document {:>10.2?}",
+            tools, baseline, indexed
+        );
+    }
+}
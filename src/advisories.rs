@@ -0,0 +1,264 @@
+use crate::{json, version, ToolVersions};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Event {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Affected {
+    pub ecosystem: String,
+    pub package: String,
+    pub ranges: Vec<Vec<Event>>,
+    pub versions: Vec<String>,
+}
+
+impl Affected {
+    pub fn affects(&self, version: &str) -> bool {
+        self.versions.iter().any(|affected| affected == version)
+            || self
+                .ranges
+                .iter()
+                .any(|events| range_affects(events, version))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Advisory {
+    pub id: String,
+    pub aliases: Vec<String>,
+    pub summary: Option<String>,
+    pub affected: Vec<Affected>,
+}
+
+impl Advisory {
+    pub fn parse(input: &str) -> io::Result<Self> {
+        let value = json::parse(input).map_err(invalid_data)?;
+
+        let id = value
+            .get("id")
+            .and_then(json::Value::as_str)
+            .ok_or_else(|| invalid_data("advisory is missing an id".to_string()))?;
+
+        let affected = value
+            .get("affected")
+            .map_or(&[][..], json::Value::as_array)
+            .iter()
+            .filter_map(|affected| {
+                let package = affected.get("package")?;
+
+                Some(Affected {
+                    ecosystem: package.get("ecosystem")?.as_str()?.to_string(),
+                    package: package.get("name")?.as_str()?.to_string(),
+                    ranges: affected
+                        .get("ranges")
+                        .map_or(&[][..], json::Value::as_array)
+                        .iter()
+                        .filter(|range| {
+                            range.get("type").and_then(json::Value::as_str) != Some("GIT")
+                        })
+                        .map(|range| {
+                            range
+                                .get("events")
+                                .map_or(&[][..], json::Value::as_array)
+                                .iter()
+                                .filter_map(parse_event)
+                                .collect()
+                        })
+                        .collect(),
+                    versions: strings(affected.get("versions")),
+                })
+            })
+            .collect();
+
+        Ok(Advisory {
+            id: id.to_string(),
+            aliases: strings(value.get("aliases")),
+            summary: value
+                .get("summary")
+                .and_then(json::Value::as_str)
+                .map(String::from),
+            affected,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PackageMapping {
+    packages: HashMap<(String, String), String>,
+}
+
+impl Default for PackageMapping {
+    fn default() -> Self {
+        PackageMapping::new()
+            .with("Go", "stdlib", "golang")
+            .with("Go", "toolchain", "golang")
+    }
+}
+
+impl PackageMapping {
+    pub fn new() -> Self {
+        PackageMapping {
+            packages: HashMap::new(),
+        }
+    }
+
+    pub fn with(mut self, ecosystem: &str, package: &str, tool_name: &str) -> Self {
+        self.packages.insert(
+            (ecosystem.to_string(), package.to_string()),
+            tool_name.to_string(),
+        );
+        self
+    }
+
+    pub fn tool_name(&self, ecosystem: &str, package: &str) -> Option<&String> {
+        self.packages
+            .get(&(ecosystem.to_string(), package.to_string()))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Database {
+    pub advisories: Vec<Advisory>,
+}
+
+impl Database {
+    pub fn load_dir<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut paths = vec![];
+        collect_json_files(path.as_ref(), &mut paths)?;
+        paths.sort();
+
+        let advisories = paths
+            .iter()
+            .map(|path| {
+                Advisory::parse(&fs::read_to_string(path)?).map_err(|error| {
+                    io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+                })
+            })
+            .collect::<io::Result<Vec<Advisory>>>()?;
+
+        Ok(Database { advisories })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Finding {
+    pub tool_name: String,
+    pub version: String,
+    pub line: Option<usize>,
+    pub advisory_ids: Vec<String>,
+}
+
+pub fn audit(tools: &ToolVersions, database: &Database, mapping: &PackageMapping) -> Vec<Finding> {
+    let mut findings = vec![];
+
+    for tool_name in tools.tools() {
+        for version in tools.versions(&tool_name).unwrap_or_default() {
+            let advisory_ids: Vec<String> = database
+                .advisories
+                .iter()
+                .filter(|advisory| {
+                    advisory.affected.iter().any(|affected| {
+                        mapping.tool_name(&affected.ecosystem, &affected.package)
+                            == Some(&tool_name)
+                            && affected.affects(&version)
+                    })
+                })
+                .map(|advisory| advisory.id.clone())
+                .collect();
+
+            if !advisory_ids.is_empty() {
+                findings.push(Finding {
+                    line: tools.line_number(&tool_name),
+                    tool_name: tool_name.clone(),
+                    version,
+                    advisory_ids,
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+fn range_affects(events: &[Event], version: &str) -> bool {
+    let mut affected = false;
+
+    for event in events {
+        match event {
+            Event::Introduced(introduced) => {
+                if introduced == "0"
+                    || version::compare_semver(version, introduced) != Ordering::Less
+                {
+                    affected = true;
+                }
+            }
+            Event::Fixed(fixed) | Event::Limit(fixed) => {
+                if version::compare_semver(version, fixed) != Ordering::Less {
+                    affected = false;
+                }
+            }
+            Event::LastAffected(last_affected) => {
+                if version::compare_semver(version, last_affected) == Ordering::Greater {
+                    affected = false;
+                }
+            }
+        }
+    }
+
+    affected
+}
+
+fn parse_event(event: &json::Value) -> Option<Event> {
+    let value = |key| {
+        event
+            .get(key)
+            .and_then(json::Value::as_str)
+            .map(String::from)
+    };
+
+    value("introduced")
+        .map(Event::Introduced)
+        .or_else(|| value("fixed").map(Event::Fixed))
+        .or_else(|| value("last_affected").map(Event::LastAffected))
+        .or_else(|| value("limit").map(Event::Limit))
+}
+
+fn strings(value: Option<&json::Value>) -> Vec<String> {
+    value
+        .map_or(&[][..], json::Value::as_array)
+        .iter()
+        .filter_map(json::Value::as_str)
+        .map(String::from)
+        .collect()
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn collect_json_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_json_files(&path, paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
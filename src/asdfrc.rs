@@ -0,0 +1,103 @@
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Concurrency {
+    Auto,
+    Jobs(usize),
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Config {
+    settings: HashMap<String, String>,
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Self {
+        let settings = input
+            .lines()
+            .filter_map(|line| {
+                let line = match line.split_once('#') {
+                    Some((line, _)) => line,
+                    None => line,
+                };
+
+                let (key, value) = line.split_once('=')?;
+                let key = key.trim();
+
+                if key.is_empty() {
+                    return None;
+                }
+
+                Some((key.to_string(), value.trim().to_string()))
+            })
+            .collect();
+
+        Config { settings }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Config::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn path_from_env() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("ASDF_CONFIG_FILE").filter(|path| !path.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+
+        env::var_os("HOME").map(|home| Path::new(&home).join(".asdfrc"))
+    }
+
+    pub fn from_env() -> io::Result<Self> {
+        let Some(path) = Config::path_from_env() else {
+            return Ok(Config::default());
+        };
+
+        match Config::from_file(path) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            result => result,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.settings.get(key).map(String::as_str)
+    }
+
+    pub fn legacy_version_file(&self) -> bool {
+        self.flag("legacy_version_file")
+    }
+
+    pub fn use_release_candidates(&self) -> bool {
+        self.flag("use_release_candidates")
+    }
+
+    pub fn always_keep_download(&self) -> bool {
+        self.flag("always_keep_download")
+    }
+
+    pub fn disable_plugin_short_name_repository(&self) -> bool {
+        self.flag("disable_plugin_short_name_repository")
+    }
+
+    pub fn plugin_repository_last_check_duration(&self) -> Option<u64> {
+        match self.get("plugin_repository_last_check_duration") {
+            Some("never") => None,
+            Some(minutes) => Some(minutes.parse().unwrap_or(60)),
+            None => Some(60),
+        }
+    }
+
+    pub fn concurrency(&self) -> Concurrency {
+        match self.get("concurrency").map(str::parse) {
+            Some(Ok(jobs)) if jobs > 0 => Concurrency::Jobs(jobs),
+            _ => Concurrency::Auto,
+        }
+    }
+
+    fn flag(&self, key: &str) -> bool {
+        matches!(self.get(key), Some("yes"))
+    }
+}
@@ -1,4 +1,7 @@
-#[derive(Debug, PartialEq)]
+use crate::parser;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct AST {
     pub lines: Vec<Line>,
 }
@@ -19,6 +22,46 @@ pub enum Line {
         error: SyntaxError,
         unparsed: Unparsed,
     },
+    Conflict {
+        error: SyntaxError,
+        ours_marker: Unparsed,
+        ours: Vec<Line>,
+        base_marker: Option<Unparsed>,
+        base: Vec<Line>,
+        separator: Unparsed,
+        theirs: Vec<Line>,
+        theirs_marker: Unparsed,
+    },
+}
+
+impl Line {
+    pub fn line_count(&self) -> usize {
+        match self {
+            Line::Conflict {
+                ours,
+                base_marker,
+                base,
+                theirs,
+                ..
+            } => 3 + ours.len() + base_marker.iter().count() + base.len() + theirs.len(),
+            _ => 1,
+        }
+    }
+}
+
+impl AST {
+    pub fn numbered_lines(&self) -> Vec<(usize, &Line)> {
+        let mut number = 1;
+
+        self.lines
+            .iter()
+            .map(|line| {
+                let numbered = (number, line);
+                number += line.line_count();
+                numbered
+            })
+            .collect()
+    }
 }
 
 pub trait Node<T> {
@@ -29,6 +72,12 @@ pub trait Node<T> {
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct Identifier(String);
 
+impl Identifier {
+    pub fn try_new(value: &str) -> Result<Self, SyntaxError> {
+        parser::validate(value, "Identifier")
+    }
+}
+
 impl Node<String> for Identifier {
     fn new(value: String) -> Self {
         Self(value)
@@ -55,6 +104,12 @@ impl Node<Vec<(Whitespace, Version)>> for Versions {
 #[derive(Debug, PartialEq, Clone)]
 pub struct Version(String);
 
+impl Version {
+    pub fn try_new(value: &str) -> Result<Self, SyntaxError> {
+        parser::validate(value, "Version")
+    }
+}
+
 impl Node<String> for Version {
     fn new(value: String) -> Self {
         Self(value)
@@ -96,4 +151,22 @@ pub enum SyntaxError {
     UnexpectedToken { token: char, expected: &'static str },
     UnexpectedEOL { expected: &'static str },
     DuplicateIdentifier(Identifier),
+    ConflictMarker,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SyntaxError::UnexpectedToken { token, expected } => {
+                write!(f, "unexpected token '{}', expected {}", token, expected)
+            }
+            SyntaxError::UnexpectedEOL { expected } => {
+                write!(f, "unexpected end of line, expected {}", expected)
+            }
+            SyntaxError::DuplicateIdentifier(identifier) => {
+                write!(f, "duplicate identifier '{}'", identifier.value())
+            }
+            SyntaxError::ConflictMarker => write!(f, "unresolved merge conflict"),
+        }
+    }
 }
@@ -0,0 +1,29 @@
+use std::{env, fs, process};
+use tool_versions::{merge, parser};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let [base, ours, theirs] = args.as_slice() else {
+        eprintln!("usage: tool-versions-merge <base> <ours> <theirs>");
+        process::exit(2);
+    };
+
+    let parse = |path: &String| {
+        parser::parse_file(path).unwrap_or_else(|error| {
+            eprintln!("tool-versions-merge: {}: {}", path, error);
+            process::exit(2);
+        })
+    };
+
+    let result = merge::merge(&parse(base), &parse(ours), &parse(theirs));
+
+    if let Err(error) = fs::write(ours, result.write()) {
+        eprintln!("tool-versions-merge: {}: {}", ours, error);
+        process::exit(2);
+    }
+
+    if !result.is_clean() {
+        process::exit(1);
+    }
+}
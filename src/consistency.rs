@@ -0,0 +1,134 @@
+use crate::{
+    ast::{Identifier, SyntaxError, Version},
+    ToolVersions,
+};
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Exception {
+    pub tool_name: String,
+    pub path: Option<PathBuf>,
+}
+
+impl Exception {
+    fn applies(&self, tool_name: &str, path: &Path) -> bool {
+        self.tool_name == tool_name
+            && self
+                .path
+                .as_ref()
+                .is_none_or(|prefix| path.starts_with(prefix))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pin {
+    pub path: PathBuf,
+    pub line: usize,
+    pub versions: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Inconsistency {
+    pub tool_name: String,
+    pub pins: Vec<Pin>,
+}
+
+impl Inconsistency {
+    pub fn distinct_versions(&self) -> Vec<&Vec<String>> {
+        let mut versions: Vec<&Vec<String>> = vec![];
+
+        for pin in &self.pins {
+            if !versions.contains(&&pin.versions) {
+                versions.push(&pin.versions);
+            }
+        }
+
+        versions
+    }
+}
+
+pub fn check(
+    files: &BTreeMap<PathBuf, ToolVersions>,
+    exceptions: &[Exception],
+) -> Vec<Inconsistency> {
+    let mut pins: BTreeMap<String, Vec<Pin>> = BTreeMap::new();
+
+    for (path, tools) in files {
+        for tool_name in tools.tools() {
+            if exceptions
+                .iter()
+                .any(|exception| exception.applies(&tool_name, path))
+            {
+                continue;
+            }
+
+            let (Some(versions), Some(line)) =
+                (tools.versions(&tool_name), tools.line_number(&tool_name))
+            else {
+                continue;
+            };
+
+            pins.entry(tool_name).or_default().push(Pin {
+                path: path.clone(),
+                line,
+                versions,
+            });
+        }
+    }
+
+    pins.into_iter()
+        .filter(|(_, pins)| pins.iter().any(|pin| pin.versions != pins[0].versions))
+        .map(|(tool_name, pins)| Inconsistency { tool_name, pins })
+        .collect()
+}
+
+pub fn align(
+    files: &mut BTreeMap<PathBuf, ToolVersions>,
+    tool_name: &str,
+    versions: Vec<&str>,
+    exceptions: &[Exception],
+) -> Result<Vec<PathBuf>, SyntaxError> {
+    Identifier::try_new(tool_name)?;
+
+    for version in &versions {
+        Version::try_new(version)?;
+    }
+
+    let expected: Vec<String> = versions.iter().map(|version| version.to_string()).collect();
+    let changed: Vec<PathBuf> = files
+        .iter()
+        .filter(|(path, _)| {
+            !exceptions
+                .iter()
+                .any(|exception| exception.applies(tool_name, path))
+        })
+        .filter(|(_, tools)| {
+            tools
+                .versions(tool_name)
+                .is_some_and(|current| current != expected)
+        })
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    for path in &changed {
+        if let Some(tools) = files.get_mut(path) {
+            tools.set_versions(tool_name, versions.clone())?;
+        }
+    }
+
+    Ok(changed)
+}
+
+pub fn save(files: &BTreeMap<PathBuf, ToolVersions>, paths: &[PathBuf]) -> io::Result<()> {
+    for path in paths {
+        if let Some(tools) = files.get(path) {
+            tools.write_file(path)?;
+        }
+    }
+
+    Ok(())
+}
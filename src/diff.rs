@@ -0,0 +1,435 @@
+use crate::{
+    ast::{Line, Node, SyntaxError},
+    json::Value,
+    ToolVersions,
+};
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Options {
+    pub whitespace: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Change {
+    ToolAdded {
+        tool_name: String,
+        line: usize,
+        versions: Vec<String>,
+    },
+    ToolRemoved {
+        tool_name: String,
+        line: usize,
+        versions: Vec<String>,
+    },
+    VersionsChanged {
+        tool_name: String,
+        line: usize,
+        old: Vec<String>,
+        new: Vec<String>,
+        added: Vec<String>,
+        removed: Vec<String>,
+        reordered: bool,
+    },
+    ToolCommentChanged {
+        tool_name: String,
+        line: usize,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    WhitespaceChanged {
+        tool_name: String,
+        line: usize,
+    },
+    CommentAdded {
+        line: usize,
+        comment: String,
+    },
+    CommentRemoved {
+        line: usize,
+        comment: String,
+    },
+    SyntaxErrorAdded {
+        line: usize,
+        error: SyntaxError,
+        unparsed: String,
+    },
+}
+
+struct Tool<'a> {
+    name: &'a str,
+    line: usize,
+    versions: Vec<String>,
+    layout: Vec<&'a str>,
+    comment: Option<&'a String>,
+}
+
+pub fn diff(old: &ToolVersions, new: &ToolVersions, options: &Options) -> Vec<Change> {
+    let old_tools = tools(old);
+    let new_tools = tools(new);
+    let mut changes = vec![];
+
+    for old_tool in &old_tools {
+        if !new_tools.iter().any(|tool| tool.name == old_tool.name) {
+            changes.push(Change::ToolRemoved {
+                tool_name: old_tool.name.to_string(),
+                line: old_tool.line,
+                versions: old_tool.versions.clone(),
+            });
+        }
+    }
+
+    for new_tool in &new_tools {
+        let Some(old_tool) = old_tools.iter().find(|tool| tool.name == new_tool.name) else {
+            changes.push(Change::ToolAdded {
+                tool_name: new_tool.name.to_string(),
+                line: new_tool.line,
+                versions: new_tool.versions.clone(),
+            });
+            continue;
+        };
+
+        if old_tool.versions != new_tool.versions {
+            let added = subtract(&new_tool.versions, &old_tool.versions);
+            let removed = subtract(&old_tool.versions, &new_tool.versions);
+            let old_common = subtract(&old_tool.versions, &removed);
+            let new_common = subtract(&new_tool.versions, &added);
+
+            changes.push(Change::VersionsChanged {
+                tool_name: new_tool.name.to_string(),
+                line: new_tool.line,
+                old: old_tool.versions.clone(),
+                new: new_tool.versions.clone(),
+                added,
+                removed,
+                reordered: old_common != new_common,
+            });
+        }
+
+        if !same_comment(old_tool.comment, new_tool.comment, options) {
+            changes.push(Change::ToolCommentChanged {
+                tool_name: new_tool.name.to_string(),
+                line: new_tool.line,
+                old: old_tool.comment.cloned(),
+                new: new_tool.comment.cloned(),
+            });
+        }
+
+        if options.whitespace && old_tool.layout != new_tool.layout {
+            changes.push(Change::WhitespaceChanged {
+                tool_name: new_tool.name.to_string(),
+                line: new_tool.line,
+            });
+        }
+    }
+
+    let old_comments = comments(old, options);
+    let new_comments = comments(new, options);
+
+    for (line, comment) in unmatched(&old_comments, &new_comments) {
+        changes.push(Change::CommentRemoved { line, comment });
+    }
+
+    for (line, comment) in unmatched(&new_comments, &old_comments) {
+        changes.push(Change::CommentAdded { line, comment });
+    }
+
+    let old_invalid = invalid_lines(old);
+
+    for (line, (error, unparsed)) in unmatched(&invalid_lines(new), &old_invalid) {
+        changes.push(Change::SyntaxErrorAdded {
+            line,
+            error,
+            unparsed,
+        });
+    }
+
+    changes
+}
+
+pub fn render(changes: &[Change]) -> String {
+    let mut s = String::new();
+
+    for change in changes {
+        let line = match change {
+            Change::ToolAdded {
+                tool_name,
+                line,
+                versions,
+            } => format!("+ {} {} (line {})", tool_name, versions.join(" "), line),
+            Change::ToolRemoved {
+                tool_name,
+                line,
+                versions,
+            } => format!("- {} {} (line {})", tool_name, versions.join(" "), line),
+            Change::VersionsChanged {
+                tool_name,
+                line,
+                old,
+                new,
+                added,
+                removed,
+                reordered,
+            } => {
+                let mut details = vec![];
+
+                if !added.is_empty() {
+                    details.push(format!("added {}", added.join(", ")));
+                }
+
+                if !removed.is_empty() {
+                    details.push(format!("removed {}", removed.join(", ")));
+                }
+
+                if *reordered {
+                    details.push("reordered".to_string());
+                }
+
+                format!(
+                    "~ {} {} -> {} (line {}; {})",
+                    tool_name,
+                    old.join(" "),
+                    new.join(" "),
+                    line,
+                    details.join("; ")
+                )
+            }
+            Change::ToolCommentChanged {
+                tool_name,
+                line,
+                old,
+                new,
+            } => format!(
+                "~ {} comment {} -> {} (line {})",
+                tool_name,
+                render_comment(old.as_ref()),
+                render_comment(new.as_ref()),
+                line
+            ),
+            Change::WhitespaceChanged { tool_name, line } => {
+                format!("~ {} whitespace (line {})", tool_name, line)
+            }
+            Change::CommentAdded { line, comment } => {
+                format!("+ #{} (line {})", comment, line)
+            }
+            Change::CommentRemoved { line, comment } => {
+                format!("- #{} (line {})", comment, line)
+            }
+            Change::SyntaxErrorAdded {
+                line,
+                error,
+                unparsed,
+            } => format!("! {} (line {}: {:?})", error, line, unparsed),
+        };
+
+        s.push_str(&line);
+        s.push('\n');
+    }
+
+    s
+}
+
+pub fn to_json(changes: &[Change]) -> String {
+    Value::Array(changes.iter().map(change_to_json).collect()).to_json()
+}
+
+fn change_to_json(change: &Change) -> Value {
+    match change {
+        Change::ToolAdded {
+            tool_name,
+            line,
+            versions,
+        } => Value::object(vec![
+            ("type", Value::string("tool_added")),
+            ("tool", Value::string(tool_name)),
+            ("line", Value::Number(line.to_string())),
+            ("versions", Value::strings(versions)),
+        ]),
+        Change::ToolRemoved {
+            tool_name,
+            line,
+            versions,
+        } => Value::object(vec![
+            ("type", Value::string("tool_removed")),
+            ("tool", Value::string(tool_name)),
+            ("line", Value::Number(line.to_string())),
+            ("versions", Value::strings(versions)),
+        ]),
+        Change::VersionsChanged {
+            tool_name,
+            line,
+            old,
+            new,
+            added,
+            removed,
+            reordered,
+        } => Value::object(vec![
+            ("type", Value::string("versions_changed")),
+            ("tool", Value::string(tool_name)),
+            ("line", Value::Number(line.to_string())),
+            ("old", Value::strings(old)),
+            ("new", Value::strings(new)),
+            ("added", Value::strings(added)),
+            ("removed", Value::strings(removed)),
+            ("reordered", Value::Bool(*reordered)),
+        ]),
+        Change::ToolCommentChanged {
+            tool_name,
+            line,
+            old,
+            new,
+        } => Value::object(vec![
+            ("type", Value::string("tool_comment_changed")),
+            ("tool", Value::string(tool_name)),
+            ("line", Value::Number(line.to_string())),
+            ("old", Value::optional_string(old.as_ref())),
+            ("new", Value::optional_string(new.as_ref())),
+        ]),
+        Change::WhitespaceChanged { tool_name, line } => Value::object(vec![
+            ("type", Value::string("whitespace_changed")),
+            ("tool", Value::string(tool_name)),
+            ("line", Value::Number(line.to_string())),
+        ]),
+        Change::CommentAdded { line, comment } => Value::object(vec![
+            ("type", Value::string("comment_added")),
+            ("line", Value::Number(line.to_string())),
+            ("comment", Value::string(comment)),
+        ]),
+        Change::CommentRemoved { line, comment } => Value::object(vec![
+            ("type", Value::string("comment_removed")),
+            ("line", Value::Number(line.to_string())),
+            ("comment", Value::string(comment)),
+        ]),
+        Change::SyntaxErrorAdded {
+            line,
+            error,
+            unparsed,
+        } => Value::object(vec![
+            ("type", Value::string("syntax_error_added")),
+            ("line", Value::Number(line.to_string())),
+            ("error", Value::string(&error.to_string())),
+            ("unparsed", Value::string(unparsed)),
+        ]),
+    }
+}
+
+fn render_comment(comment: Option<&String>) -> String {
+    match comment {
+        Some(comment) => format!("{:?}", comment),
+        None => "none".to_string(),
+    }
+}
+
+fn tools(tools: &ToolVersions) -> Vec<Tool<'_>> {
+    tools
+        .document
+        .ast()
+        .numbered_lines()
+        .into_iter()
+        .filter_map(|(number, line)| match line {
+            Line::ToolDefinition {
+                name,
+                versions,
+                whitespace,
+                comment,
+            } => {
+                let mut layout: Vec<&str> = versions
+                    .value()
+                    .iter()
+                    .map(|(padding, _)| padding.value().as_str())
+                    .collect();
+                layout.push(whitespace.as_ref().map_or("", |w| w.value().as_str()));
+
+                Some(Tool {
+                    name: name.value(),
+                    line: number,
+                    versions: versions
+                        .value()
+                        .iter()
+                        .map(|(_, version)| version.value().clone())
+                        .collect(),
+                    layout,
+                    comment: comment.as_ref().map(|comment| comment.value()),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn comments(tools: &ToolVersions, options: &Options) -> Vec<(usize, String)> {
+    tools
+        .document
+        .ast()
+        .numbered_lines()
+        .into_iter()
+        .filter_map(|(number, line)| match line {
+            Line::Empty {
+                comment: Some(comment),
+                ..
+            } => Some((number, normalize(comment.value(), options))),
+            _ => None,
+        })
+        .collect()
+}
+
+fn invalid_lines(tools: &ToolVersions) -> Vec<(usize, (SyntaxError, String))> {
+    tools
+        .document
+        .ast()
+        .numbered_lines()
+        .into_iter()
+        .filter_map(|(number, line)| match line {
+            Line::Invalid { error, unparsed } => {
+                Some((number, (error.clone(), unparsed.value().clone())))
+            }
+            Line::Conflict { error, .. } => Some((number, (error.clone(), line.to_source()))),
+            _ => None,
+        })
+        .collect()
+}
+
+fn same_comment(old: Option<&String>, new: Option<&String>, options: &Options) -> bool {
+    old.map(|comment| normalize(comment, options)) == new.map(|comment| normalize(comment, options))
+}
+
+fn normalize(comment: &str, options: &Options) -> String {
+    if options.whitespace {
+        comment.to_string()
+    } else {
+        comment.trim().to_string()
+    }
+}
+
+fn subtract<T: PartialEq + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    let mut remaining: Vec<&T> = right.iter().collect();
+
+    left.iter()
+        .filter(
+            |item| match remaining.iter().position(|other| other == item) {
+                Some(i) => {
+                    remaining.remove(i);
+                    false
+                }
+                None => true,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+fn unmatched<T: PartialEq + Clone>(left: &[(usize, T)], right: &[(usize, T)]) -> Vec<(usize, T)> {
+    let mut remaining: Vec<&T> = right.iter().map(|(_, item)| item).collect();
+
+    left.iter()
+        .filter(
+            |(_, item)| match remaining.iter().position(|other| *other == item) {
+                Some(i) => {
+                    remaining.remove(i);
+                    false
+                }
+                None => true,
+            },
+        )
+        .cloned()
+        .collect()
+}
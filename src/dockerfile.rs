@@ -0,0 +1,280 @@
+use crate::ToolVersions;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default)]
+pub struct ArgMapping {
+    overrides: HashMap<String, String>,
+}
+
+impl ArgMapping {
+    pub fn new() -> Self {
+        ArgMapping {
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn with(mut self, tool_name: &str, arg_name: &str) -> Self {
+        self.overrides
+            .insert(tool_name.to_string(), arg_name.to_string());
+        self
+    }
+
+    pub fn arg_name(&self, tool_name: &str) -> String {
+        if let Some(arg_name) = self.overrides.get(tool_name) {
+            return arg_name.clone();
+        }
+
+        let prefix = match tool_name {
+            "nodejs" => "NODE".to_string(),
+            "golang" => "GO".to_string(),
+            _ => tool_name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect(),
+        };
+
+        format!("{}_VERSION", prefix)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Arg {
+    pub line: usize,
+    pub name: String,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Drift {
+    pub tool_name: String,
+    pub arg: Arg,
+    pub expected: String,
+}
+
+struct ArgToken {
+    line: usize,
+    name: String,
+    value: Option<String>,
+    value_start: usize,
+    value_end: usize,
+    quote: Option<char>,
+}
+
+pub fn parse_args(dockerfile: &str) -> Vec<Arg> {
+    parse_arg_tokens(dockerfile)
+        .into_iter()
+        .map(|token| Arg {
+            line: token.line,
+            name: token.name,
+            value: token.value,
+        })
+        .collect()
+}
+
+pub fn drift(dockerfile: &str, tools: &ToolVersions, mapping: &ArgMapping) -> Vec<Drift> {
+    let expected = expected_args(tools, mapping);
+
+    parse_args(dockerfile)
+        .into_iter()
+        .filter_map(|arg| {
+            let (tool_name, version) = expected.get(&arg.name)?;
+
+            if arg.value.as_ref() == Some(version) {
+                return None;
+            }
+
+            Some(Drift {
+                tool_name: tool_name.clone(),
+                arg,
+                expected: version.clone(),
+            })
+        })
+        .collect()
+}
+
+pub fn update(dockerfile: &str, tools: &ToolVersions, mapping: &ArgMapping) -> String {
+    let expected = expected_args(tools, mapping);
+    let mut result = dockerfile.to_string();
+
+    for token in parse_arg_tokens(dockerfile).into_iter().rev() {
+        let Some((_, version)) = expected.get(&token.name) else {
+            continue;
+        };
+
+        let replacement = match token.quote {
+            Some(quote) => format!("{}{}{}", quote, version, quote),
+            None if token.value.is_none() => format!("={}", version),
+            None => version.clone(),
+        };
+
+        result.replace_range(token.value_start..token.value_end, &replacement);
+    }
+
+    result
+}
+
+pub fn arg_block(tools: &ToolVersions, mapping: &ArgMapping) -> String {
+    tools
+        .tools()
+        .iter()
+        .filter_map(|tool_name| {
+            primary_version(tools, tool_name)
+                .map(|version| format!("ARG {}={}\n", mapping.arg_name(tool_name), version))
+        })
+        .collect()
+}
+
+fn expected_args(tools: &ToolVersions, mapping: &ArgMapping) -> HashMap<String, (String, String)> {
+    tools
+        .tools()
+        .into_iter()
+        .filter_map(|tool_name| {
+            let version = primary_version(tools, &tool_name)?;
+            Some((mapping.arg_name(&tool_name), (tool_name, version)))
+        })
+        .collect()
+}
+
+fn primary_version(tools: &ToolVersions, tool_name: &str) -> Option<String> {
+    tools
+        .versions(tool_name)?
+        .into_iter()
+        .find(|version| version != "system" && !version.starts_with("path:"))
+}
+
+fn parse_arg_tokens(dockerfile: &str) -> Vec<ArgToken> {
+    let mut tokens = vec![];
+    let mut offset = 0;
+    let mut lines = dockerfile.split_inclusive('\n');
+
+    while let Some(line) = lines.next() {
+        let start = offset;
+        let mut end = offset + line.len();
+        let mut last = line;
+
+        while is_continued(last) {
+            let Some(next) = lines.next() else {
+                break;
+            };
+
+            end += next.len();
+            last = next;
+        }
+
+        offset = end;
+
+        let instruction = &dockerfile[start..end];
+        let trimmed = instruction.trim_start();
+        let arguments_start = start + instruction.len() - trimmed.len() + 3;
+
+        let is_arg = trimmed
+            .get(..3)
+            .is_some_and(|keyword| keyword.eq_ignore_ascii_case("ARG"))
+            && trimmed[3..].starts_with(|c: char| c.is_whitespace());
+
+        if is_arg {
+            tokens.extend(tokenize(dockerfile, arguments_start, end));
+        }
+    }
+
+    tokens
+}
+
+fn is_continued(line: &str) -> bool {
+    line.trim_end().ends_with('\\')
+}
+
+fn tokenize(dockerfile: &str, start: usize, end: usize) -> Vec<ArgToken> {
+    let text = &dockerfile[..end];
+    let mut tokens = vec![];
+    let mut chars = text[start..]
+        .char_indices()
+        .map(|(i, c)| (start + i, c))
+        .peekable();
+
+    loop {
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() || is_line_continuation(text, i) {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let Some(&(word_start, _)) = chars.peek() else {
+            break;
+        };
+
+        let mut word_end = end;
+        let mut quote = None;
+
+        while let Some(&(i, c)) = chars.peek() {
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if c.is_whitespace() || is_line_continuation(text, i) => {
+                    word_end = i;
+                    break;
+                }
+                None if c == '"' || c == '\'' => quote = Some(c),
+                None if c == '\\' => {
+                    chars.next();
+                }
+                None => {}
+            }
+
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            word_end = end;
+        }
+
+        let word = text[word_start..word_end].trim_end();
+        let word_end = word_start + word.len();
+        let line = text[..word_start].matches('\n').count() + 1;
+
+        tokens.push(match word.split_once('=') {
+            Some((name, value)) => {
+                let quote = value.chars().next().filter(|c| {
+                    (*c == '"' || *c == '\'') && value.len() > 1 && value.ends_with(*c)
+                });
+
+                ArgToken {
+                    line,
+                    name: name.to_string(),
+                    value: Some(match quote {
+                        Some(_) => value[1..value.len() - 1].to_string(),
+                        None => value.replace(['"', '\''], ""),
+                    }),
+                    value_start: word_start + name.len() + 1,
+                    value_end: word_end,
+                    quote,
+                }
+            }
+            None => ArgToken {
+                line,
+                name: word.to_string(),
+                value: None,
+                value_start: word_end,
+                value_end: word_end,
+                quote: None,
+            },
+        });
+    }
+
+    tokens
+}
+
+fn is_line_continuation(text: &str, i: usize) -> bool {
+    text[i..].starts_with('\\')
+        && text[i + 1..]
+            .trim_start_matches([' ', '\t', '\r'])
+            .starts_with('\n')
+}
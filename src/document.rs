@@ -0,0 +1,331 @@
+use crate::ast::{
+    Identifier, Line, Node, SyntaxError, Unparsed, Version, Versions, Whitespace, AST,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Document {
+    ast: AST,
+    index: HashMap<Identifier, Vec<usize>>,
+    conflicted: HashSet<Identifier>,
+}
+
+impl Document {
+    pub fn new(ast: AST) -> Self {
+        let mut index = HashMap::new();
+        let mut conflicted = HashSet::new();
+
+        for (i, line) in ast.lines.iter().enumerate() {
+            match line {
+                Line::ToolDefinition { name, .. } => {
+                    index.entry(name.clone()).or_insert_with(Vec::new).push(i);
+                }
+                Line::Conflict { ours, theirs, .. } => {
+                    for line in ours.iter().chain(theirs) {
+                        if let Line::ToolDefinition { name, .. } = line {
+                            conflicted.insert(name.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Document {
+            ast,
+            index,
+            conflicted,
+        }
+    }
+
+    pub fn ast(&self) -> &AST {
+        &self.ast
+    }
+
+    pub fn into_ast(self) -> AST {
+        self.ast
+    }
+
+    pub fn line_index(&self, tool_name: &Identifier) -> Option<usize> {
+        self.line_indices(tool_name).first().copied()
+    }
+
+    pub fn line_indices(&self, tool_name: &Identifier) -> &[usize] {
+        self.index
+            .get(tool_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn is_conflicted(&self, tool_name: &Identifier) -> bool {
+        self.conflicted.contains(tool_name)
+    }
+
+    pub fn versions(&self, tool_name: &Identifier) -> Option<&Versions> {
+        match self.ast.lines.get(self.line_index(tool_name)?) {
+            Some(Line::ToolDefinition { versions, .. }) => Some(versions),
+            _ => None,
+        }
+    }
+
+    pub fn set_versions(
+        &mut self,
+        tool_name: Identifier,
+        versions: Vec<Version>,
+    ) -> Result<(), SyntaxError> {
+        self.check(&tool_name)?;
+
+        for version in &versions {
+            Version::try_new(version.value())?;
+        }
+
+        let indices = self.line_indices(&tool_name).to_vec();
+
+        if indices.is_empty() {
+            if !versions.is_empty() {
+                self.index
+                    .insert(tool_name.clone(), vec![self.ast.lines.len()]);
+                self.ast.lines.push(Line::ToolDefinition {
+                    name: tool_name,
+                    versions: Versions::new(
+                        versions
+                            .into_iter()
+                            .map(|version| (Whitespace::new(" ".to_string()), version))
+                            .collect(),
+                    ),
+                    whitespace: None,
+                    comment: None,
+                });
+            }
+
+            return Ok(());
+        }
+
+        if versions.is_empty() {
+            for i in indices.into_iter().rev() {
+                self.remove_line(i);
+            }
+
+            return Ok(());
+        }
+
+        for i in indices {
+            if let Line::ToolDefinition {
+                versions: old_versions,
+                ..
+            } = &mut self.ast.lines[i]
+            {
+                *old_versions = Versions::new(pad(old_versions.value(), &versions));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn edit_versions<F: FnOnce(Vec<Version>) -> Vec<Version>>(
+        &mut self,
+        tool_name: Identifier,
+        edit: F,
+    ) -> Result<(), SyntaxError> {
+        let versions = self
+            .versions(&tool_name)
+            .map(|versions| {
+                versions
+                    .value()
+                    .iter()
+                    .map(|(_, version)| version.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.set_versions(tool_name, edit(versions))
+    }
+
+    pub fn append_version(
+        &mut self,
+        tool_name: Identifier,
+        version: Version,
+    ) -> Result<(), SyntaxError> {
+        self.edit_versions(tool_name, |mut versions| {
+            versions.push(version);
+            versions
+        })
+    }
+
+    pub fn prepend_version(
+        &mut self,
+        tool_name: Identifier,
+        version: Version,
+    ) -> Result<(), SyntaxError> {
+        self.edit_versions(tool_name, |mut versions| {
+            versions.insert(0, version);
+            versions
+        })
+    }
+
+    pub fn remove_version(
+        &mut self,
+        tool_name: Identifier,
+        version: Version,
+    ) -> Result<(), SyntaxError> {
+        self.edit_versions(tool_name, |mut versions| {
+            versions.retain(|existing| *existing != version);
+            versions
+        })
+    }
+
+    pub fn promote_version(
+        &mut self,
+        tool_name: Identifier,
+        version: Version,
+    ) -> Result<(), SyntaxError> {
+        self.edit_versions(tool_name, |mut versions| {
+            if versions.contains(&version) {
+                versions.retain(|existing| *existing != version);
+                versions.insert(0, version);
+            }
+
+            versions
+        })
+    }
+
+    pub fn dedupe_versions(&mut self, tool_name: Identifier) -> Result<(), SyntaxError> {
+        self.edit_versions(tool_name, |versions| {
+            let mut deduped: Vec<Version> = vec![];
+
+            for version in versions {
+                if !deduped.contains(&version) {
+                    deduped.push(version);
+                }
+            }
+
+            deduped
+        })
+    }
+
+    pub fn rename(&mut self, from: &Identifier, to: Identifier) -> Result<bool, SyntaxError> {
+        self.check(from)?;
+        self.check(&to)?;
+
+        if self.index.contains_key(&to) {
+            return Ok(false);
+        }
+
+        let Some(indices) = self.index.remove(from) else {
+            return Ok(false);
+        };
+
+        for &i in &indices {
+            if let Line::ToolDefinition { name, .. } = &mut self.ast.lines[i] {
+                *name = to.clone();
+            }
+        }
+
+        self.index.insert(to, indices);
+        Ok(true)
+    }
+
+    pub fn set_comment(
+        &mut self,
+        tool_name: &Identifier,
+        comment: Option<Unparsed>,
+    ) -> Result<bool, SyntaxError> {
+        self.check(tool_name)?;
+
+        if let Some(token) = comment
+            .as_ref()
+            .and_then(|comment| comment.value().chars().find(|c| matches!(c, '\n' | '\r')))
+        {
+            return Err(SyntaxError::UnexpectedToken {
+                token,
+                expected: "Comment",
+            });
+        }
+
+        let indices = self.line_indices(tool_name).to_vec();
+
+        for &i in &indices {
+            if let Line::ToolDefinition {
+                whitespace,
+                comment: old_comment,
+                ..
+            } = &mut self.ast.lines[i]
+            {
+                match &comment {
+                    Some(comment) => {
+                        if whitespace.is_none() {
+                            *whitespace = Some(Whitespace::new(" ".to_string()));
+                        }
+
+                        *old_comment = Some(comment.clone());
+                    }
+                    None => {
+                        if old_comment.take().is_some() {
+                            *whitespace = None;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(!indices.is_empty())
+    }
+
+    fn check(&self, tool_name: &Identifier) -> Result<(), SyntaxError> {
+        Identifier::try_new(tool_name.value())?;
+
+        if self.is_conflicted(tool_name) {
+            return Err(SyntaxError::ConflictMarker);
+        }
+
+        Ok(())
+    }
+
+    fn remove_line(&mut self, i: usize) {
+        if let Line::ToolDefinition { name, .. } = self.ast.lines.remove(i) {
+            if let Some(indices) = self.index.get_mut(&name) {
+                indices.retain(|&index| index != i);
+
+                if indices.is_empty() {
+                    self.index.remove(&name);
+                }
+            }
+        }
+
+        for index in self.index.values_mut().flatten() {
+            if *index > i {
+                *index -= 1;
+            }
+        }
+    }
+}
+
+fn pad(old: &[(Whitespace, Version)], versions: &[Version]) -> Vec<(Whitespace, Version)> {
+    let mut unused: Vec<bool> = vec![true; old.len()];
+    let mut paddings: Vec<Option<Whitespace>> = versions
+        .iter()
+        .map(|version| {
+            let k = (0..old.len()).find(|&k| unused[k] && old[k].1 == *version)?;
+            unused[k] = false;
+            Some(old[k].0.clone())
+        })
+        .collect();
+
+    for (i, padding) in paddings.iter_mut().enumerate() {
+        if padding.is_none() && unused.get(i) == Some(&true) {
+            unused[i] = false;
+            *padding = Some(old[i].0.clone());
+        }
+    }
+
+    paddings
+        .into_iter()
+        .zip(versions.iter().cloned())
+        .map(|(padding, version)| {
+            (
+                padding.unwrap_or_else(|| Whitespace::new(" ".to_string())),
+                version,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use crate::{
+    inventory::{Inventory, Status},
+    resolver::Resolution,
+    shell,
+};
+use std::{
+    env::{self, JoinPathsError},
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Environment {
+    pub path: Vec<PathBuf>,
+    pub vars: Vec<(String, String)>,
+}
+
+impl Environment {
+    pub fn path_value(&self, existing: Option<&OsStr>) -> Result<OsString, JoinPathsError> {
+        let mut paths = self.path.clone();
+
+        if let Some(existing) = existing {
+            paths.extend(env::split_paths(existing));
+        }
+
+        env::join_paths(paths)
+    }
+
+    pub fn apply<'a>(&self, command: &'a mut Command) -> Result<&'a mut Command, JoinPathsError> {
+        let existing = command
+            .get_envs()
+            .find(|(key, _)| *key == "PATH")
+            .and_then(|(_, value)| value.map(OsStr::to_os_string))
+            .or_else(|| env::var_os("PATH"));
+
+        command.env("PATH", self.path_value(existing.as_deref())?);
+
+        for (key, value) in &self.vars {
+            command.env(key, value);
+        }
+
+        Ok(command)
+    }
+}
+
+pub fn environment(resolutions: &[Resolution], inventory: &Inventory) -> Environment {
+    let mut environment = Environment::default();
+
+    for resolution in resolutions {
+        let Some((version, status)) = &resolution.selected else {
+            continue;
+        };
+
+        let install_path = match status {
+            Status::Installed => Some(inventory.install_path(&resolution.tool_name, version)),
+            Status::Path { .. } => version.strip_prefix("path:").map(PathBuf::from),
+            _ => None,
+        };
+
+        if let Some(install_path) = install_path {
+            environment.path.push(bin_path(&install_path));
+        }
+
+        environment
+            .vars
+            .push((shell::variable_name(&resolution.tool_name), version.clone()));
+    }
+
+    environment
+}
+
+pub fn command<S: AsRef<OsStr>>(
+    program: S,
+    environment: &Environment,
+) -> Result<Command, JoinPathsError> {
+    let mut command = Command::new(program);
+    environment.apply(&mut command)?;
+    Ok(command)
+}
+
+fn bin_path(install_path: &Path) -> PathBuf {
+    let bin = install_path.join("bin");
+
+    if bin.is_dir() {
+        bin
+    } else {
+        install_path.to_path_buf()
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{asdfrc::Config, files::Locations, shell, ToolVersions};
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub cwd: PathBuf,
+    pub locations: Locations,
+    pub config: Config,
+    pub env_vars: HashMap<String, String>,
+    pub legacy_filenames: HashMap<String, Vec<String>>,
+}
+
+impl Context {
+    pub fn new<P: AsRef<Path>>(cwd: P, locations: Locations, config: Config) -> Self {
+        Context {
+            cwd: cwd.as_ref().to_path_buf(),
+            locations,
+            config,
+            env_vars: HashMap::new(),
+            legacy_filenames: default_legacy_filenames(),
+        }
+    }
+
+    pub fn from_env() -> io::Result<Self> {
+        let mut context = Context::new(
+            env::current_dir()?,
+            Locations::from_env(),
+            Config::from_env()?,
+        );
+        context.env_vars = env::vars().collect();
+        Ok(context)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Source {
+    EnvVar { name: String },
+    File { path: PathBuf, line: usize },
+    LegacyFile { path: PathBuf },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Step {
+    EnvVar {
+        name: String,
+        value: Option<String>,
+    },
+    File {
+        path: PathBuf,
+        global: bool,
+        found: Option<(usize, Vec<String>)>,
+    },
+    LegacyFile {
+        path: PathBuf,
+        version: Option<String>,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Explanation {
+    pub tool_name: String,
+    pub steps: Vec<Step>,
+    pub source: Option<Source>,
+    pub versions: Option<Vec<String>>,
+}
+
+pub fn explain(tool_name: &str, context: &Context) -> io::Result<Explanation> {
+    let mut explanation = Explanation {
+        tool_name: tool_name.to_string(),
+        steps: vec![],
+        source: None,
+        versions: None,
+    };
+
+    let name = shell::variable_name(tool_name);
+    let value = context
+        .env_vars
+        .get(&name)
+        .filter(|value| !value.is_empty())
+        .cloned();
+
+    explanation.steps.push(Step::EnvVar {
+        name: name.clone(),
+        value: value.clone(),
+    });
+
+    if let Some(value) = value {
+        explanation.source = Some(Source::EnvVar { name });
+        explanation.versions = Some(value.split_whitespace().map(String::from).collect());
+        return Ok(explanation);
+    }
+
+    let global = context.locations.global().ok();
+
+    for dir in context.cwd.ancestors() {
+        let path = context.locations.local(dir);
+
+        if check_file(&mut explanation, &path, global.as_ref() == Some(&path))? {
+            return Ok(explanation);
+        }
+
+        if context.config.legacy_version_file()
+            && check_legacy_files(&mut explanation, dir, context)?
+        {
+            return Ok(explanation);
+        }
+    }
+
+    if let Some(global) = global {
+        let visited = explanation
+            .steps
+            .iter()
+            .any(|step| matches!(step, Step::File { path, .. } if *path == global));
+
+        if !visited {
+            check_file(&mut explanation, &global, true)?;
+        }
+    }
+
+    Ok(explanation)
+}
+
+pub fn render(explanation: &Explanation) -> String {
+    let mut s = String::from(&explanation.tool_name);
+
+    match &explanation.versions {
+        Some(versions) => {
+            s.push(' ');
+            s.push_str(&versions.join(" "));
+        }
+        None => s.push_str(": no version set"),
+    }
+
+    s.push('\n');
+
+    for step in &explanation.steps {
+        let line = match step {
+            Step::EnvVar { name, value: None } => format!("{} is not set", name),
+            Step::EnvVar {
+                name,
+                value: Some(value),
+            } => format!("{} is set to \"{}\" (selected)", name, value),
+            Step::File {
+                path,
+                global,
+                found: None,
+            } => format!(
+                "{}{}: no entry",
+                path.display(),
+                if *global { " (global)" } else { "" }
+            ),
+            Step::File {
+                path,
+                global,
+                found: Some((line, versions)),
+            } => format!(
+                "{}:{}{}: {} {} (selected)",
+                path.display(),
+                line,
+                if *global { " (global)" } else { "" },
+                explanation.tool_name,
+                versions.join(" ")
+            ),
+            Step::LegacyFile {
+                path,
+                version: None,
+            } => format!("{}: empty legacy file", path.display()),
+            Step::LegacyFile {
+                path,
+                version: Some(version),
+            } => format!("{}: legacy file {} (selected)", path.display(), version),
+        };
+
+        s.push_str("  ");
+        s.push_str(&line);
+        s.push('\n');
+    }
+
+    s
+}
+
+fn check_file(explanation: &mut Explanation, path: &Path, global: bool) -> io::Result<bool> {
+    let tools = match ToolVersions::from_file(path) {
+        Ok(tools) => tools,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(error) => return Err(error),
+    };
+
+    let found = tools
+        .line_number(&explanation.tool_name)
+        .zip(tools.versions(&explanation.tool_name));
+
+    explanation.steps.push(Step::File {
+        path: path.to_path_buf(),
+        global,
+        found: found.clone(),
+    });
+
+    let Some((line, versions)) = found else {
+        return Ok(false);
+    };
+
+    explanation.source = Some(Source::File {
+        path: path.to_path_buf(),
+        line,
+    });
+    explanation.versions = Some(versions);
+
+    Ok(true)
+}
+
+fn check_legacy_files(
+    explanation: &mut Explanation,
+    dir: &Path,
+    context: &Context,
+) -> io::Result<bool> {
+    let Some(filenames) = context.legacy_filenames.get(&explanation.tool_name) else {
+        return Ok(false);
+    };
+
+    for filename in filenames {
+        let path = dir.join(filename);
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error),
+        };
+
+        let version = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(String::from);
+
+        explanation.steps.push(Step::LegacyFile {
+            path: path.clone(),
+            version: version.clone(),
+        });
+
+        if let Some(version) = version {
+            explanation.source = Some(Source::LegacyFile { path });
+            explanation.versions = Some(vec![version]);
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn default_legacy_filenames() -> HashMap<String, Vec<String>> {
+    [
+        ("nodejs", vec![".nvmrc", ".node-version"]),
+        ("ruby", vec![".ruby-version"]),
+        ("python", vec![".python-version"]),
+        ("golang", vec![".go-version"]),
+        ("java", vec![".java-version"]),
+        ("terraform", vec![".terraform-version"]),
+    ]
+    .into_iter()
+    .map(|(tool_name, filenames)| {
+        (
+            tool_name.to_string(),
+            filenames.into_iter().map(String::from).collect(),
+        )
+    })
+    .collect()
+}
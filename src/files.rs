@@ -0,0 +1,92 @@
+use crate::ToolVersions;
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_FILENAME: &str = ".tool-versions";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Locations {
+    pub home: Option<PathBuf>,
+    pub filename: String,
+}
+
+impl Locations {
+    pub fn new<P: AsRef<Path>>(home: P, filename: &str) -> Self {
+        Locations {
+            home: Some(home.as_ref().to_path_buf()),
+            filename: filename.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Locations {
+            home: env::var_os("HOME")
+                .filter(|home| !home.is_empty())
+                .map(PathBuf::from),
+            filename: env::var("ASDF_DEFAULT_TOOL_VERSIONS_FILENAME")
+                .ok()
+                .filter(|filename| !filename.is_empty())
+                .unwrap_or_else(|| DEFAULT_FILENAME.to_string()),
+        }
+    }
+
+    pub fn global(&self) -> io::Result<PathBuf> {
+        match &self.home {
+            Some(home) => Ok(home.join(&self.filename)),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "HOME is not set, cannot locate the global tool versions file",
+            )),
+        }
+    }
+
+    pub fn local<P: AsRef<Path>>(&self, dir: P) -> PathBuf {
+        dir.as_ref().join(&self.filename)
+    }
+
+    pub fn set_global(&self, tool_name: &str, versions: Vec<&str>) -> io::Result<()> {
+        set(self.global()?, tool_name, versions)
+    }
+
+    pub fn unset_global(&self, tool_name: &str) -> io::Result<()> {
+        unset(self.global()?, tool_name)
+    }
+
+    pub fn set_local<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        tool_name: &str,
+        versions: Vec<&str>,
+    ) -> io::Result<()> {
+        set(self.local(dir), tool_name, versions)
+    }
+
+    pub fn unset_local<P: AsRef<Path>>(&self, dir: P, tool_name: &str) -> io::Result<()> {
+        unset(self.local(dir), tool_name)
+    }
+}
+
+pub fn load_or_new<P: AsRef<Path>>(path: P) -> io::Result<ToolVersions> {
+    match ToolVersions::from_file(path) {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(ToolVersions::new()),
+        result => result,
+    }
+}
+
+pub fn set<P: AsRef<Path>>(path: P, tool_name: &str, versions: Vec<&str>) -> io::Result<()> {
+    let mut tools = load_or_new(&path)?;
+    tools
+        .set_versions(tool_name, versions)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error.to_string()))?;
+    tools.write_file(path)
+}
+
+pub fn unset<P: AsRef<Path>>(path: P, tool_name: &str) -> io::Result<()> {
+    if !path.as_ref().exists() {
+        return Ok(());
+    }
+
+    set(path, tool_name, vec![])
+}
@@ -0,0 +1,210 @@
+use crate::ToolVersions;
+
+const DEFAULT_JAVA_DISTRIBUTION: &str = "temurin";
+
+struct SetupAction {
+    uses: &'static str,
+    input: &'static str,
+}
+
+fn setup_action(tool_name: &str) -> Option<SetupAction> {
+    match tool_name {
+        "nodejs" | "node" => Some(SetupAction {
+            uses: "actions/setup-node@v4",
+            input: "node-version",
+        }),
+        "python" => Some(SetupAction {
+            uses: "actions/setup-python@v5",
+            input: "python-version",
+        }),
+        "ruby" => Some(SetupAction {
+            uses: "ruby/setup-ruby@v1",
+            input: "ruby-version",
+        }),
+        "golang" | "go" => Some(SetupAction {
+            uses: "actions/setup-go@v5",
+            input: "go-version",
+        }),
+        "java" => Some(SetupAction {
+            uses: "actions/setup-java@v4",
+            input: "java-version",
+        }),
+        _ => None,
+    }
+}
+
+pub fn setup_steps(tools: &ToolVersions) -> String {
+    let mut s = String::new();
+
+    for tool_name in tools.tools() {
+        if let Some(version) = installable_versions(tools, &tool_name).first() {
+            push_step(&mut s, &tool_name, Value::Literal(version));
+        }
+    }
+
+    s
+}
+
+pub fn matrix_setup_steps(tools: &ToolVersions) -> String {
+    let mut s = String::new();
+
+    for tool_name in tools.tools() {
+        if !installable_versions(tools, &tool_name).is_empty() {
+            push_step(&mut s, &tool_name, Value::Matrix(&tool_name));
+        }
+    }
+
+    s
+}
+
+pub fn matrix(tools: &ToolVersions) -> String {
+    let mut s = String::new();
+
+    for tool_name in tools.tools() {
+        let versions = installable_versions(tools, &tool_name);
+
+        if versions.is_empty() {
+            continue;
+        }
+
+        s.push_str("    ");
+        s.push_str(&quote_key(&tool_name));
+        s.push_str(": [");
+        s.push_str(
+            &versions
+                .iter()
+                .map(|version| matrix_value(&tool_name, version))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+        s.push_str("]\n");
+    }
+
+    if s.is_empty() {
+        return s;
+    }
+
+    format!("strategy:\n  matrix:\n{}", s)
+}
+
+fn matrix_value(tool_name: &str, version: &str) -> String {
+    if !is_java(tool_name) {
+        return quote(version);
+    }
+
+    let (distribution, version) = split_java_distribution(version);
+
+    format!(
+        "{{ distribution: {}, version: {} }}",
+        quote(distribution.unwrap_or(DEFAULT_JAVA_DISTRIBUTION)),
+        quote(version)
+    )
+}
+
+enum Value<'a> {
+    Literal(&'a str),
+    Matrix(&'a str),
+}
+
+fn push_step(s: &mut String, tool_name: &str, value: Value) {
+    let Some(action) = setup_action(tool_name) else {
+        return;
+    };
+
+    s.push_str("- uses: ");
+    s.push_str(action.uses);
+    s.push_str("\n  with:\n");
+
+    match value {
+        Value::Literal(version) => {
+            let (distribution, version) = if is_java(tool_name) {
+                split_java_distribution(version)
+            } else {
+                (None, version)
+            };
+
+            if is_java(tool_name) {
+                s.push_str("    distribution: ");
+                s.push_str(&quote(distribution.unwrap_or(DEFAULT_JAVA_DISTRIBUTION)));
+                s.push('\n');
+            }
+
+            s.push_str("    ");
+            s.push_str(action.input);
+            s.push_str(": ");
+            s.push_str(&quote(version));
+            s.push('\n');
+        }
+        Value::Matrix(key) => {
+            let expression = matrix_expression(key);
+
+            if is_java(tool_name) {
+                s.push_str("    distribution: ${{ ");
+                s.push_str(&expression);
+                s.push_str(".distribution }}\n");
+            }
+
+            s.push_str("    ");
+            s.push_str(action.input);
+            s.push_str(": ${{ ");
+            s.push_str(&expression);
+
+            if is_java(tool_name) {
+                s.push_str(".version");
+            }
+
+            s.push_str(" }}\n");
+        }
+    }
+}
+
+fn is_java(tool_name: &str) -> bool {
+    setup_action(tool_name).is_some_and(|action| action.input == "java-version")
+}
+
+fn installable_versions(tools: &ToolVersions, tool_name: &str) -> Vec<String> {
+    tools
+        .versions(tool_name)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|version| version != "system" && !version.starts_with("path:"))
+        .collect()
+}
+
+fn split_java_distribution(version: &str) -> (Option<&str>, &str) {
+    match version.split_once('-') {
+        Some((distribution, rest))
+            if !distribution.is_empty()
+                && distribution.chars().all(|c| c.is_ascii_alphabetic())
+                && rest.starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+            (Some(distribution), rest)
+        }
+        _ => (None, version),
+    }
+}
+
+fn is_plain_key(key: &str) -> bool {
+    key.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn quote_key(key: &str) -> String {
+    if is_plain_key(key) {
+        key.to_string()
+    } else {
+        quote(key)
+    }
+}
+
+fn matrix_expression(key: &str) -> String {
+    if is_plain_key(key) {
+        format!("matrix.{}", key)
+    } else {
+        format!("matrix['{}']", key.replace('\'', "''"))
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -0,0 +1,161 @@
+use crate::{upgrade::VersionIndex, ToolVersions};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Status {
+    Installed,
+    NotInstalled,
+    PluginMissing,
+    System,
+    Path { exists: bool },
+}
+
+impl Status {
+    pub fn is_usable(&self) -> bool {
+        matches!(
+            self,
+            Status::Installed | Status::System | Status::Path { exists: true }
+        )
+    }
+}
+
+pub trait Installs {
+    fn status(&self, tool_name: &str, version: &str) -> Status;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ToolStatus {
+    pub tool_name: String,
+    pub versions: Vec<(String, Status)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    data_dir: PathBuf,
+}
+
+impl Inventory {
+    pub fn new<P: AsRef<Path>>(data_dir: P) -> Self {
+        Inventory {
+            data_dir: data_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        if let Some(data_dir) = env::var_os("ASDF_DATA_DIR").filter(|dir| !dir.is_empty()) {
+            return Some(Inventory::new(data_dir));
+        }
+
+        env::var_os("HOME").map(|home| Inventory::new(Path::new(&home).join(".asdf")))
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    pub fn plugins(&self) -> io::Result<Vec<String>> {
+        list_dir(&self.data_dir.join("plugins"))
+    }
+
+    pub fn has_plugin(&self, tool_name: &str) -> bool {
+        self.data_dir.join("plugins").join(tool_name).is_dir()
+    }
+
+    pub fn installed_versions(&self, tool_name: &str) -> io::Result<Vec<String>> {
+        list_dir(&self.data_dir.join("installs").join(tool_name))
+    }
+
+    pub fn install_path(&self, tool_name: &str, version: &str) -> PathBuf {
+        let dir_name = match version.strip_prefix("ref:") {
+            Some(reference) => format!("ref-{}", reference),
+            None => version.to_string(),
+        };
+
+        self.data_dir
+            .join("installs")
+            .join(tool_name)
+            .join(dir_name)
+    }
+
+    pub fn is_installed(&self, tool_name: &str, version: &str) -> bool {
+        self.install_path(tool_name, version).is_dir()
+    }
+
+    pub fn report(&self, tools: &ToolVersions) -> Vec<ToolStatus> {
+        tools
+            .tools()
+            .into_iter()
+            .map(|tool_name| {
+                let versions = tools
+                    .versions(&tool_name)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|version| {
+                        let status = self.status(&tool_name, &version);
+                        (version, status)
+                    })
+                    .collect();
+
+                ToolStatus {
+                    tool_name,
+                    versions,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Installs for Inventory {
+    fn status(&self, tool_name: &str, version: &str) -> Status {
+        if version == "system" {
+            return Status::System;
+        }
+
+        if let Some(path) = version.strip_prefix("path:") {
+            return Status::Path {
+                exists: Path::new(path).is_dir(),
+            };
+        }
+
+        if !self.has_plugin(tool_name) {
+            return Status::PluginMissing;
+        }
+
+        if self.is_installed(tool_name, version) {
+            Status::Installed
+        } else {
+            Status::NotInstalled
+        }
+    }
+}
+
+impl VersionIndex for Inventory {
+    fn available_versions(&self, tool_name: &str) -> io::Result<Vec<String>> {
+        self.installed_versions(tool_name)
+    }
+}
+
+fn list_dir(path: &Path) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+
+    let mut names = vec![];
+
+    for entry in entries {
+        let entry = entry?;
+
+        if entry.path().is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    names.sort();
+
+    Ok(names)
+}
@@ -0,0 +1,126 @@
+use crate::{
+    asdfrc::Config,
+    ast::SyntaxError,
+    upgrade::{self, Upgrade, VersionIndex},
+    version, ToolVersions,
+};
+use std::io;
+
+const UNSTABLE_MARKERS: [&str; 14] = [
+    "-src",
+    "-dev",
+    "-latest",
+    "-stm",
+    "-rc",
+    ".rc",
+    "-milestone",
+    "-alpha",
+    "-beta",
+    "-pre",
+    ".pre",
+    "-next",
+    "snapshot",
+    "master",
+];
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Options {
+    pub include_unstable: bool,
+}
+
+impl Options {
+    pub fn from_config(config: &Config) -> Self {
+        Options {
+            include_unstable: config.use_release_candidates(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Resolved {
+    pub tool_name: String,
+    pub line: Option<usize>,
+    pub spec: String,
+    pub version: Option<String>,
+}
+
+pub fn prefix(spec: &str) -> Option<&str> {
+    if spec == "latest" {
+        return Some("");
+    }
+
+    spec.strip_prefix("latest:")
+}
+
+pub fn is_unstable(version: &str) -> bool {
+    let version = version.to_lowercase();
+
+    UNSTABLE_MARKERS
+        .iter()
+        .any(|marker| version.contains(marker))
+        || version.char_indices().any(|(i, c)| {
+            matches!(c, 'a' | 'b' | 'c')
+                && version[i + 1..].starts_with(|c: char| c.is_ascii_digit())
+        })
+}
+
+pub fn resolve_spec(spec: &str, available: &[String], options: &Options) -> Option<String> {
+    let prefix = prefix(spec)?;
+
+    available
+        .iter()
+        .filter(|version| version.starts_with(prefix))
+        .filter(|version| options.include_unstable || !is_unstable(version))
+        .max_by(|left, right| version::compare(left, right))
+        .cloned()
+}
+
+pub fn resolve<I: VersionIndex>(
+    tools: &ToolVersions,
+    index: &I,
+    options: &Options,
+) -> io::Result<Vec<Resolved>> {
+    let mut resolved = vec![];
+
+    for tool_name in tools.tools() {
+        let specs: Vec<String> = tools
+            .versions(&tool_name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|version| prefix(version).is_some())
+            .collect();
+
+        if specs.is_empty() {
+            continue;
+        }
+
+        let available = index.available_versions(&tool_name)?;
+
+        for spec in specs {
+            resolved.push(Resolved {
+                line: tools.line_number(&tool_name),
+                tool_name: tool_name.clone(),
+                version: resolve_spec(&spec, &available, options),
+                spec,
+            });
+        }
+    }
+
+    Ok(resolved)
+}
+
+pub fn pin(tools: &mut ToolVersions, resolved: &[Resolved]) -> Result<(), SyntaxError> {
+    let upgrades: Vec<Upgrade> = resolved
+        .iter()
+        .filter_map(|resolved| {
+            Some(Upgrade {
+                tool_name: resolved.tool_name.clone(),
+                line: resolved.line,
+                from: resolved.spec.clone(),
+                to: resolved.version.clone()?,
+            })
+        })
+        .collect();
+
+    upgrade::apply(tools, &upgrades)
+}
@@ -1,73 +1,191 @@
 use crate::ast::Node;
 use std::{io, path::Path};
 
+pub mod advisories;
+pub mod asdfrc;
 pub mod ast;
+pub mod consistency;
+pub mod diff;
+pub mod dockerfile;
+pub mod document;
+pub mod exec;
+pub mod explain;
+pub mod files;
+pub mod fingerprint;
+pub mod github_actions;
+pub mod inventory;
+mod json;
+pub mod latest;
+pub mod lifecycle;
+pub mod lock;
+pub mod merge;
 pub mod parser;
+pub mod policy;
+pub mod resolver;
+pub mod scanner;
+pub mod shell;
+pub mod transaction;
 pub mod transformer;
+pub mod upgrade;
+pub mod version;
 pub mod writer;
 
 pub struct ToolVersions {
-    ast: ast::AST,
+    document: document::Document,
+}
+
+impl Default for ToolVersions {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ToolVersions {
     pub fn new() -> Self {
         ToolVersions {
-            ast: ast::AST { lines: vec![] },
+            document: document::Document::new(ast::AST { lines: vec![] }),
         }
     }
 
     pub fn from(s: &str) -> Self {
         ToolVersions {
-            ast: parser::parse(s),
+            document: document::Document::new(parser::parse(s)),
         }
     }
 
     pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
         let ast = parser::parse_file(path)?;
-        Ok(ToolVersions { ast })
+        Ok(ToolVersions {
+            document: document::Document::new(ast),
+        })
     }
 
     pub fn errors(&self) -> Vec<&ast::SyntaxError> {
-        self.ast
+        self.document
+            .ast()
             .lines
             .iter()
             .filter_map(|line| match line {
-                ast::Line::Invalid { error, .. } => Some(error),
+                ast::Line::Invalid { error, .. } | ast::Line::Conflict { error, .. } => Some(error),
                 _ => None,
             })
             .collect()
     }
 
-    pub fn versions(&self, tool_name: &str) -> Option<Vec<String>> {
-        self.ast.lines.iter().find_map(|line| match line {
-            ast::Line::ToolDefinition { name, versions, .. } if name.value() == tool_name => Some(
-                versions
-                    .value()
-                    .iter()
-                    .map(|(_, v)| v.value().clone())
-                    .collect(),
-            ),
-            _ => None,
-        })
+    pub fn tools(&self) -> Vec<String> {
+        self.document
+            .ast()
+            .lines
+            .iter()
+            .filter_map(|line| match line {
+                ast::Line::ToolDefinition { name, .. } => Some(name.value().clone()),
+                _ => None,
+            })
+            .collect()
     }
 
-    pub fn set_versions(&mut self, tool_name: &str, versions: Vec<&str>) {
-        self.ast = transformer::set_versions(
-            &self.ast,
-            ast::Identifier::new(tool_name.to_string()),
+    pub fn versions(&self, tool_name: &str) -> Option<Vec<String>> {
+        let versions = self
+            .document
+            .versions(&ast::Identifier::new(tool_name.to_string()))?;
+
+        Some(
             versions
+                .value()
                 .iter()
-                .map(|version| ast::Version::new(version.to_string()))
+                .map(|(_, v)| v.value().clone())
                 .collect(),
-        );
+        )
+    }
+
+    pub fn line_number(&self, tool_name: &str) -> Option<usize> {
+        self.document
+            .ast()
+            .numbered_lines()
+            .into_iter()
+            .find_map(|(number, line)| match line {
+                ast::Line::ToolDefinition { name, .. } if name.value() == tool_name => Some(number),
+                _ => None,
+            })
+    }
+
+    pub fn set_versions(
+        &mut self,
+        tool_name: &str,
+        versions: Vec<&str>,
+    ) -> Result<(), ast::SyntaxError> {
+        self.document.set_versions(
+            ast::Identifier::try_new(tool_name)?,
+            versions
+                .iter()
+                .map(|version| ast::Version::try_new(version))
+                .collect::<Result<Vec<ast::Version>, ast::SyntaxError>>()?,
+        )
+    }
+
+    pub fn append_version(
+        &mut self,
+        tool_name: &str,
+        version: &str,
+    ) -> Result<(), ast::SyntaxError> {
+        self.document.append_version(
+            ast::Identifier::try_new(tool_name)?,
+            ast::Version::try_new(version)?,
+        )
+    }
+
+    pub fn prepend_version(
+        &mut self,
+        tool_name: &str,
+        version: &str,
+    ) -> Result<(), ast::SyntaxError> {
+        self.document.prepend_version(
+            ast::Identifier::try_new(tool_name)?,
+            ast::Version::try_new(version)?,
+        )
+    }
+
+    pub fn remove_version(
+        &mut self,
+        tool_name: &str,
+        version: &str,
+    ) -> Result<(), ast::SyntaxError> {
+        self.document.remove_version(
+            ast::Identifier::try_new(tool_name)?,
+            ast::Version::try_new(version)?,
+        )
+    }
+
+    pub fn promote_version(
+        &mut self,
+        tool_name: &str,
+        version: &str,
+    ) -> Result<(), ast::SyntaxError> {
+        self.document.promote_version(
+            ast::Identifier::try_new(tool_name)?,
+            ast::Version::try_new(version)?,
+        )
+    }
+
+    pub fn dedupe_versions(&mut self, tool_name: &str) -> Result<(), ast::SyntaxError> {
+        self.document
+            .dedupe_versions(ast::Identifier::try_new(tool_name)?)
+    }
+
+    pub fn transaction(&mut self) -> transaction::Transaction<'_> {
+        transaction::Transaction::new(self)
+    }
+
+    pub fn resolve_conflicts(&mut self, resolution: transformer::ConflictResolution) {
+        let ast = transformer::resolve_conflicts(self.document.ast(), resolution);
+        self.document = document::Document::new(parser::parse(&writer::write(&ast)));
     }
 
     pub fn write(&self) -> String {
-        writer::write(&self.ast)
+        writer::write(self.document.ast())
     }
 
     pub fn write_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
-        writer::write_file(&self.ast, path)
+        writer::write_file(self.document.ast(), path)
     }
 }
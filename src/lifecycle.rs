@@ -0,0 +1,206 @@
+use crate::{json, ToolVersions};
+use std::{collections::HashMap, fmt, fs, io, path::Path};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Date { year, month, day }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+
+        Some(Date { year, month, day })
+    }
+
+    pub fn days_since_epoch(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    pub fn days_until(&self, other: &Date) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Eol {
+    Date(Date),
+    Reached,
+    Supported,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cycle {
+    pub cycle: String,
+    pub eol: Eol,
+    pub lts: bool,
+}
+
+impl Cycle {
+    pub fn matches(&self, version: &str) -> bool {
+        match version.strip_prefix(&self.cycle) {
+            Some(rest) => rest.is_empty() || rest.starts_with(['.', '-', '+']),
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Dataset {
+    pub tools: HashMap<String, Vec<Cycle>>,
+}
+
+impl Dataset {
+    pub fn parse_cycles(input: &str) -> io::Result<Vec<Cycle>> {
+        let value = json::parse(input)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(value
+            .as_array()
+            .iter()
+            .filter_map(|cycle| {
+                let name = match cycle.get("cycle")? {
+                    json::Value::String(name) => name.clone(),
+                    json::Value::Number(number) => number.clone(),
+                    _ => return None,
+                };
+
+                let eol = match cycle.get("eol") {
+                    Some(json::Value::String(date)) => Eol::Date(Date::parse(date)?),
+                    Some(json::Value::Bool(true)) => Eol::Reached,
+                    _ => Eol::Supported,
+                };
+
+                let lts = match cycle.get("lts") {
+                    Some(json::Value::Bool(lts)) => *lts,
+                    Some(json::Value::String(_)) => true,
+                    _ => false,
+                };
+
+                Some(Cycle {
+                    cycle: name,
+                    eol,
+                    lts,
+                })
+            })
+            .collect())
+    }
+
+    pub fn load_dir<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut tools = HashMap::new();
+
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let Some(product) = path.file_stem().map(|stem| stem.to_string_lossy()) else {
+                continue;
+            };
+
+            let tool_name = match product.as_ref() {
+                "go" => "golang".to_string(),
+                "node" => "nodejs".to_string(),
+                product => product.to_string(),
+            };
+
+            tools.insert(
+                tool_name,
+                Dataset::parse_cycles(&fs::read_to_string(&path)?)?,
+            );
+        }
+
+        Ok(Dataset { tools })
+    }
+
+    pub fn cycle(&self, tool_name: &str, version: &str) -> Option<&Cycle> {
+        self.tools
+            .get(tool_name)?
+            .iter()
+            .filter(|cycle| cycle.matches(version))
+            .max_by_key(|cycle| cycle.cycle.len())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Status {
+    EndOfLife(Option<Date>),
+    EndingSoon(Date),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Warning {
+    pub tool_name: String,
+    pub version: String,
+    pub line: Option<usize>,
+    pub cycle: String,
+    pub lts: bool,
+    pub status: Status,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    pub today: Date,
+    pub horizon_days: i64,
+}
+
+pub fn check(tools: &ToolVersions, dataset: &Dataset, options: &Options) -> Vec<Warning> {
+    let mut warnings = vec![];
+
+    for tool_name in tools.tools() {
+        for version in tools.versions(&tool_name).unwrap_or_default() {
+            let Some(cycle) = dataset.cycle(&tool_name, &version) else {
+                continue;
+            };
+
+            let status = match cycle.eol {
+                Eol::Reached => Status::EndOfLife(None),
+                Eol::Date(date) if date <= options.today => Status::EndOfLife(Some(date)),
+                Eol::Date(date) if options.today.days_until(&date) <= options.horizon_days => {
+                    Status::EndingSoon(date)
+                }
+                _ => continue,
+            };
+
+            warnings.push(Warning {
+                line: tools.line_number(&tool_name),
+                tool_name: tool_name.clone(),
+                version,
+                cycle: cycle.cycle.clone(),
+                lts: cycle.lts,
+                status,
+            });
+        }
+    }
+
+    warnings
+}
@@ -0,0 +1,253 @@
+use crate::{
+    files,
+    latest::{self, Options},
+    upgrade::VersionIndex,
+    version, ToolVersions,
+};
+use std::{fmt, fs, io, path::Path};
+
+pub const LOCK_FILENAME: &str = ".tool-versions.lock";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Entry {
+    pub tool_name: String,
+    pub spec: String,
+    pub version: String,
+    pub checksum: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum LockError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    Unresolved { tool_name: String, spec: String },
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LockError::Io(error) => write!(f, "{}", error),
+            LockError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            LockError::Unresolved { tool_name, spec } => {
+                write!(f, "cannot resolve {} {}", tool_name, spec)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for LockError {
+    fn from(error: io::Error) -> Self {
+        LockError::Io(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Stale {
+    Missing { tool_name: String, spec: String },
+    Extra { tool_name: String, spec: String },
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Lock {
+    pub entries: Vec<Entry>,
+}
+
+impl Lock {
+    pub fn parse(input: &str) -> Result<Self, LockError> {
+        let mut entries = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            let (tool_name, spec, version, checksum) = match words.as_slice() {
+                [] => continue,
+                [comment, ..] if comment.starts_with('#') => continue,
+                [tool_name, spec, version] => (tool_name, spec, version, None),
+                [tool_name, spec, version, checksum] => {
+                    (tool_name, spec, version, Some(checksum.to_string()))
+                }
+                _ => {
+                    return Err(LockError::Parse {
+                        line: i + 1,
+                        message: "expected tool name, spec, version and optional checksum"
+                            .to_string(),
+                    });
+                }
+            };
+
+            entries.push(Entry {
+                tool_name: tool_name.to_string(),
+                spec: spec.to_string(),
+                version: version.to_string(),
+                checksum,
+            });
+        }
+
+        Ok(Lock { entries })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, LockError> {
+        Lock::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn entry(&self, tool_name: &str, spec: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.tool_name == tool_name && entry.spec == spec)
+    }
+
+    pub fn set_checksum(&mut self, tool_name: &str, version: &str, checksum: &str) {
+        for entry in &mut self.entries {
+            if entry.tool_name == tool_name && entry.version == version {
+                entry.checksum = Some(checksum.to_string());
+            }
+        }
+    }
+
+    pub fn write(&self) -> String {
+        let mut s = format!("# generated from {}\n", files::DEFAULT_FILENAME);
+
+        for entry in &self.entries {
+            s.push_str(&format!(
+                "{} {} {}",
+                entry.tool_name, entry.spec, entry.version
+            ));
+
+            if let Some(checksum) = &entry.checksum {
+                s.push(' ');
+                s.push_str(checksum);
+            }
+
+            s.push('\n');
+        }
+
+        s
+    }
+
+    pub fn write_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.write())
+    }
+}
+
+pub fn lock<I: VersionIndex>(
+    tools: &ToolVersions,
+    index: &I,
+    previous: Option<&Lock>,
+    options: &Options,
+) -> Result<Lock, LockError> {
+    let mut entries = vec![];
+
+    for tool_name in tools.tools() {
+        let mut available = None;
+        let mut aliases = None;
+
+        for spec in tools.versions(&tool_name).unwrap_or_default() {
+            if let Some(entry) = previous.and_then(|previous| previous.entry(&tool_name, &spec)) {
+                entries.push(entry.clone());
+                continue;
+            }
+
+            if available.is_none() {
+                available = Some(index.available_versions(&tool_name)?);
+                aliases = Some(index.aliases(&tool_name)?);
+            }
+
+            let version = resolve(
+                &spec,
+                available.as_deref().unwrap_or_default(),
+                aliases.as_deref().unwrap_or_default(),
+                options,
+            )
+            .ok_or_else(|| LockError::Unresolved {
+                tool_name: tool_name.clone(),
+                spec: spec.clone(),
+            })?;
+
+            entries.push(Entry {
+                tool_name: tool_name.clone(),
+                spec,
+                version,
+                checksum: None,
+            });
+        }
+    }
+
+    Ok(Lock { entries })
+}
+
+pub fn verify(tools: &ToolVersions, lock: &Lock) -> Result<(), Vec<Stale>> {
+    let specs: Vec<(String, String)> = tools
+        .tools()
+        .into_iter()
+        .flat_map(|tool_name| {
+            tools
+                .versions(&tool_name)
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |spec| (tool_name.clone(), spec))
+        })
+        .collect();
+
+    let mut stale: Vec<Stale> = specs
+        .iter()
+        .filter(|(tool_name, spec)| lock.entry(tool_name, spec).is_none())
+        .map(|(tool_name, spec)| Stale::Missing {
+            tool_name: tool_name.clone(),
+            spec: spec.clone(),
+        })
+        .collect();
+
+    stale.extend(
+        lock.entries
+            .iter()
+            .filter(|entry| {
+                !specs
+                    .iter()
+                    .any(|(tool_name, spec)| *tool_name == entry.tool_name && *spec == entry.spec)
+            })
+            .map(|entry| Stale::Extra {
+                tool_name: entry.tool_name.clone(),
+                spec: entry.spec.clone(),
+            }),
+    );
+
+    if stale.is_empty() {
+        Ok(())
+    } else {
+        Err(stale)
+    }
+}
+
+fn resolve(
+    spec: &str,
+    available: &[String],
+    aliases: &[(String, String)],
+    options: &Options,
+) -> Option<String> {
+    if latest::prefix(spec).is_some() {
+        return latest::resolve_spec(spec, available, options);
+    }
+
+    if let Some((_, version)) = aliases.iter().find(|(alias, _)| alias == spec) {
+        return Some(version.clone());
+    }
+
+    if spec == "system"
+        || spec.starts_with("path:")
+        || spec.starts_with("ref:")
+        || available.iter().any(|version| version == spec)
+    {
+        return Some(spec.to_string());
+    }
+
+    available
+        .iter()
+        .filter(|version| {
+            version
+                .strip_prefix(spec)
+                .is_some_and(|rest| rest.starts_with('.'))
+        })
+        .filter(|version| options.include_unstable || !latest::is_unstable(version))
+        .max_by(|left, right| version::compare(left, right))
+        .cloned()
+}
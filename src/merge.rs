@@ -0,0 +1,414 @@
+use crate::ast::{Line, Node, AST};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MergeLine {
+    Line(Line),
+    Conflict {
+        tool_name: String,
+        ours: Option<Box<Line>>,
+        theirs: Option<Box<Line>>,
+    },
+    Layout {
+        ours: Vec<Line>,
+        theirs: Vec<Line>,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Merge {
+    pub lines: Vec<MergeLine>,
+}
+
+impl Merge {
+    pub fn is_clean(&self) -> bool {
+        !self
+            .lines
+            .iter()
+            .any(|line| !matches!(line, MergeLine::Line(_)))
+    }
+
+    pub fn conflicts(&self) -> Vec<&String> {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                MergeLine::Conflict { tool_name, .. } => Some(tool_name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn to_ast(&self) -> Option<AST> {
+        self.lines
+            .iter()
+            .map(|line| match line {
+                MergeLine::Line(line) => Some(line.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<Line>>>()
+            .map(|lines| AST { lines })
+    }
+
+    pub fn write(&self) -> String {
+        let mut s = String::new();
+
+        for line in &self.lines {
+            match line {
+                MergeLine::Line(line) => {
+                    s.push_str(&line.to_source());
+                    s.push('\n');
+                }
+                MergeLine::Conflict { ours, theirs, .. } => {
+                    s.push_str("<<<<<<< ours\n");
+
+                    if let Some(ours) = ours {
+                        s.push_str(&ours.to_source());
+                        s.push('\n');
+                    }
+
+                    s.push_str("=======\n");
+
+                    if let Some(theirs) = theirs {
+                        s.push_str(&theirs.to_source());
+                        s.push('\n');
+                    }
+
+                    s.push_str(">>>>>>> theirs\n");
+                }
+                MergeLine::Layout { ours, theirs } => {
+                    s.push_str("<<<<<<< ours\n");
+
+                    for line in ours {
+                        s.push_str(&line.to_source());
+                        s.push('\n');
+                    }
+
+                    s.push_str("=======\n");
+
+                    for line in theirs {
+                        s.push_str(&line.to_source());
+                        s.push('\n');
+                    }
+
+                    s.push_str(">>>>>>> theirs\n");
+                }
+            }
+        }
+
+        s
+    }
+}
+
+enum Resolution<'a> {
+    Resolved(Option<&'a Line>),
+    Conflict,
+}
+
+#[derive(PartialEq)]
+enum Key<'a> {
+    Tool(&'a str),
+    Layout(&'a Line),
+}
+
+enum Entry<'a> {
+    Tool(&'a str),
+    Line(MergeLine),
+}
+
+pub fn merge(base: &AST, ours: &AST, theirs: &AST) -> Merge {
+    let base_keys = keys(&base.lines);
+    let ours_keys = keys(&ours.lines);
+    let theirs_keys = keys(&theirs.lines);
+
+    let ours_matches = matches(&base_keys, &ours_keys);
+    let theirs_matches = matches(&base_keys, &theirs_keys);
+
+    let mut entries = vec![];
+    let mut placed = HashSet::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+
+    loop {
+        let anchor =
+            (b..base_keys.len()).find_map(|i| match (ours_matches[i], theirs_matches[i]) {
+                (Some(oi), Some(ti)) if oi >= o && ti >= t => Some((i, oi, ti)),
+                _ => None,
+            });
+        let (bi, oi, ti) = anchor.unwrap_or((base_keys.len(), ours_keys.len(), theirs_keys.len()));
+
+        merge_hunk(
+            &mut entries,
+            &mut placed,
+            &base.lines[b..bi],
+            &ours.lines[o..oi],
+            &theirs.lines[t..ti],
+        );
+
+        if anchor.is_none() {
+            break;
+        }
+
+        entries.push(entry(&ours.lines[oi]));
+        (b, o, t) = (bi + 1, oi + 1, ti + 1);
+    }
+
+    let base_tools = tool_lines(base);
+    let ours_tools = tool_lines(ours);
+    let theirs_tools = tool_lines(theirs);
+
+    let conflict = |tool_name: &str| MergeLine::Conflict {
+        tool_name: tool_name.to_string(),
+        ours: ours_tools
+            .get(tool_name)
+            .map(|line| Box::new((*line).clone())),
+        theirs: theirs_tools
+            .get(tool_name)
+            .map(|line| Box::new((*line).clone())),
+    };
+    let resolve_tool = |tool_name: &str| match resolve(
+        base_tools.get(tool_name).copied(),
+        ours_tools.get(tool_name).copied(),
+        theirs_tools.get(tool_name).copied(),
+    ) {
+        Resolution::Resolved(line) => line.map(|line| MergeLine::Line(line.clone())),
+        Resolution::Conflict => Some(conflict(tool_name)),
+    };
+
+    let mut lines = vec![];
+
+    for entry in entries {
+        match entry {
+            Entry::Line(line) => lines.push(line),
+            Entry::Tool(tool_name) => {
+                if placed.insert(tool_name) {
+                    lines.extend(resolve_tool(tool_name));
+                }
+            }
+        }
+    }
+
+    for line in ours.lines.iter().chain(&theirs.lines) {
+        let Some(tool_name) = tool_name(line) else {
+            continue;
+        };
+
+        if !placed.insert(tool_name) {
+            continue;
+        }
+
+        let Some(merge_line) = resolve_tool(tool_name) else {
+            continue;
+        };
+
+        let side = if theirs_tools.contains_key(tool_name) {
+            theirs
+        } else {
+            ours
+        };
+        let index = insertion_index(&lines, previous_tool(side, tool_name));
+        lines.insert(index, merge_line);
+    }
+
+    Merge { lines }
+}
+
+fn merge_hunk<'a>(
+    entries: &mut Vec<Entry<'a>>,
+    placed: &mut HashSet<&'a str>,
+    base: &[Line],
+    ours: &'a [Line],
+    theirs: &'a [Line],
+) {
+    let (base_keys, ours_keys, theirs_keys) = (keys(base), keys(ours), keys(theirs));
+
+    if ours_keys == base_keys {
+        entries.extend(theirs.iter().map(entry));
+        return;
+    }
+
+    if theirs_keys == base_keys || theirs_keys == ours_keys {
+        entries.extend(ours.iter().map(entry));
+        return;
+    }
+
+    let (base_layout, ours_layout, theirs_layout) = (layout(base), layout(ours), layout(theirs));
+
+    if ours_layout != base_layout && theirs_layout != base_layout && ours_layout != theirs_layout {
+        placed.extend(ours.iter().chain(theirs).filter_map(tool_name));
+        entries.push(Entry::Line(MergeLine::Layout {
+            ours: ours.to_vec(),
+            theirs: theirs.to_vec(),
+        }));
+        return;
+    }
+
+    let (skeleton, other) = if ours_layout == base_layout && theirs_layout != base_layout {
+        (theirs, ours)
+    } else {
+        (ours, theirs)
+    };
+
+    entries.extend(skeleton.iter().map(entry));
+    entries.extend(
+        other
+            .iter()
+            .filter_map(tool_name)
+            .filter(|name| !skeleton.iter().any(|line| tool_name(line) == Some(name)))
+            .map(Entry::Tool),
+    );
+}
+
+fn resolve<'a>(
+    base: Option<&'a Line>,
+    ours: Option<&'a Line>,
+    theirs: Option<&'a Line>,
+) -> Resolution<'a> {
+    if ours == theirs || ours == base {
+        return Resolution::Resolved(theirs);
+    }
+
+    if theirs == base {
+        return Resolution::Resolved(ours);
+    }
+
+    match (ours, theirs) {
+        (Some(ours), Some(theirs)) if semantically_equal(ours, theirs) => {
+            Resolution::Resolved(Some(ours))
+        }
+        _ => Resolution::Conflict,
+    }
+}
+
+fn insertion_index(lines: &[MergeLine], previous_tool: Option<&str>) -> usize {
+    let position = |tool: &str| {
+        lines.iter().position(|line| match line {
+            MergeLine::Line(line) => tool_name(line) == Some(tool),
+            MergeLine::Conflict { tool_name, .. } => tool_name == tool,
+            MergeLine::Layout { ours, theirs } => ours
+                .iter()
+                .chain(theirs)
+                .any(|line| tool_name(line) == Some(tool)),
+        })
+    };
+
+    if let Some(index) = previous_tool.and_then(position) {
+        return index + 1;
+    }
+
+    if previous_tool.is_none() {
+        if let Some(index) = lines.iter().position(|line| match line {
+            MergeLine::Line(line) => tool_name(line).is_some(),
+            MergeLine::Conflict { .. } => true,
+            MergeLine::Layout { ours, theirs } => ours
+                .iter()
+                .chain(theirs)
+                .any(|line| tool_name(line).is_some()),
+        }) {
+            return index;
+        }
+    }
+
+    lines.len()
+}
+
+fn semantically_equal(left: &Line, right: &Line) -> bool {
+    match (left, right) {
+        (
+            Line::ToolDefinition {
+                versions: left_versions,
+                comment: left_comment,
+                ..
+            },
+            Line::ToolDefinition {
+                versions: right_versions,
+                comment: right_comment,
+                ..
+            },
+        ) => {
+            left_versions
+                .value()
+                .iter()
+                .map(|(_, version)| version)
+                .eq(right_versions.value().iter().map(|(_, version)| version))
+                && left_comment.as_ref().map(|comment| comment.value().trim())
+                    == right_comment.as_ref().map(|comment| comment.value().trim())
+        }
+        _ => false,
+    }
+}
+
+fn tool_name(line: &Line) -> Option<&str> {
+    match line {
+        Line::ToolDefinition { name, .. } => Some(name.value()),
+        _ => None,
+    }
+}
+
+fn tool_lines(ast: &AST) -> HashMap<&str, &Line> {
+    ast.lines
+        .iter()
+        .filter_map(|line| tool_name(line).map(|name| (name, line)))
+        .collect()
+}
+
+fn previous_tool<'a>(ast: &'a AST, tool_name: &str) -> Option<&'a str> {
+    ast.lines
+        .iter()
+        .filter_map(self::tool_name)
+        .take_while(|name| *name != tool_name)
+        .last()
+}
+
+fn entry(line: &Line) -> Entry<'_> {
+    match tool_name(line) {
+        Some(tool_name) => Entry::Tool(tool_name),
+        None => Entry::Line(MergeLine::Line(line.clone())),
+    }
+}
+
+fn keys(lines: &[Line]) -> Vec<Key<'_>> {
+    lines
+        .iter()
+        .map(|line| match tool_name(line) {
+            Some(tool_name) => Key::Tool(tool_name),
+            None => Key::Layout(line),
+        })
+        .collect()
+}
+
+pub(crate) fn matches<T: PartialEq>(base: &[T], side: &[T]) -> Vec<Option<usize>> {
+    let mut lengths = vec![vec![0; side.len() + 1]; base.len() + 1];
+
+    for i in (0..base.len()).rev() {
+        for j in (0..side.len()).rev() {
+            lengths[i][j] = if base[i] == side[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut matches = vec![None; base.len()];
+    let (mut i, mut j) = (0, 0);
+
+    while i < base.len() && j < side.len() {
+        if base[i] == side[j] {
+            matches[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    matches
+}
+
+fn layout(lines: &[Line]) -> Vec<&Line> {
+    lines
+        .iter()
+        .filter(|line| tool_name(line).is_none())
+        .collect()
+}
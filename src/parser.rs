@@ -10,16 +10,119 @@ pub fn parse_file<P: AsRef<Path>>(path: P) -> io::Result<AST> {
 
 pub fn parse(input: &str) -> AST {
     let mut unique_identifiers = HashSet::new();
+    let lines: Vec<&str> = input.lines().collect();
 
     AST {
-        lines: input
-            .lines()
-            .map(|line| parse_line(line, &mut unique_identifiers))
-            .collect(),
+        lines: parse_lines(&lines, &mut unique_identifiers),
     }
 }
 
+fn parse_lines(lines: &[&str], unique_identifiers: &mut HashSet<Identifier>) -> Vec<Line> {
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        if let Some((conflict, line_count)) = parse_conflict(&lines[i..], unique_identifiers) {
+            result.push(conflict);
+            i += line_count;
+            continue;
+        }
+
+        result.push(parse_line(lines[i], unique_identifiers));
+        i += 1;
+    }
+
+    result
+}
+
+const OURS_MARKER: &str = "<<<<<<<";
+const BASE_MARKER: &str = "|||||||";
+const SEPARATOR: &str = "=======";
+const THEIRS_MARKER: &str = ">>>>>>>";
+
+fn is_marker(line: &str, marker: &str) -> bool {
+    match line.strip_prefix(marker) {
+        Some(rest) if marker == SEPARATOR => rest.chars().all(char::is_whitespace),
+        Some(rest) => rest.is_empty() || rest.starts_with(char::is_whitespace),
+        None => false,
+    }
+}
+
+fn parse_conflict(
+    lines: &[&str],
+    unique_identifiers: &mut HashSet<Identifier>,
+) -> Option<(Line, usize)> {
+    if !is_marker(lines.first()?, OURS_MARKER) {
+        return None;
+    }
+
+    let mut base_index = None;
+    let mut separator_index = None;
+    let mut end_index = None;
+
+    for (i, line) in lines.iter().enumerate().skip(1) {
+        if is_marker(line, OURS_MARKER) {
+            return None;
+        }
+
+        if separator_index.is_none() {
+            if is_marker(line, BASE_MARKER) && base_index.is_none() {
+                base_index = Some(i);
+            } else if is_marker(line, SEPARATOR) {
+                separator_index = Some(i);
+            }
+        } else if is_marker(line, THEIRS_MARKER) {
+            end_index = Some(i);
+            break;
+        }
+    }
+
+    let separator_index = separator_index?;
+    let end_index = end_index?;
+    let ours_end = base_index.unwrap_or(separator_index);
+
+    let parse_side = |side: &[&str]| {
+        let mut side_identifiers = unique_identifiers.clone();
+        let lines = parse_lines(side, &mut side_identifiers);
+        (lines, side_identifiers)
+    };
+
+    let (ours, ours_identifiers) = parse_side(&lines[1..ours_end]);
+    let (base, _) = match base_index {
+        Some(base_index) => parse_side(&lines[base_index + 1..separator_index]),
+        None => (Vec::new(), HashSet::new()),
+    };
+    let (theirs, theirs_identifiers) = parse_side(&lines[separator_index + 1..end_index]);
+
+    unique_identifiers.extend(ours_identifiers);
+    unique_identifiers.extend(theirs_identifiers);
+
+    Some((
+        Line::Conflict {
+            error: SyntaxError::ConflictMarker,
+            ours_marker: Unparsed::new(lines[0].to_string()),
+            ours,
+            base_marker: base_index.map(|i| Unparsed::new(lines[i].to_string())),
+            base,
+            separator: Unparsed::new(lines[separator_index].to_string()),
+            theirs,
+            theirs_marker: Unparsed::new(lines[end_index].to_string()),
+        },
+        end_index + 1,
+    ))
+}
+
 fn parse_line(line: &str, unique_identifiers: &mut HashSet<Identifier>) -> Line {
+    if [OURS_MARKER, BASE_MARKER, SEPARATOR, THEIRS_MARKER]
+        .iter()
+        .any(|marker| is_marker(line, marker))
+    {
+        return Line::Invalid {
+            error: SyntaxError::ConflictMarker,
+            unparsed: Unparsed::new(line.to_string()),
+        };
+    }
+
     let mut chars = line.chars();
 
     match chars.next() {
@@ -104,7 +207,7 @@ fn parse_definition(
                 let (whitespace, next) = consume::<Whitespace>(first, chars);
 
                 match next {
-                    None if versions.len() == 0 => {
+                    None if versions.is_empty() => {
                         return Line::Invalid {
                             error: SyntaxError::UnexpectedEOL {
                                 expected: "Version",
@@ -120,7 +223,7 @@ fn parse_definition(
                             comment: None,
                         }
                     }
-                    Some('#') if versions.len() == 0 => {
+                    Some('#') if versions.is_empty() => {
                         return Line::Invalid {
                             error: SyntaxError::UnexpectedToken {
                                 token: '#',
@@ -173,7 +276,7 @@ fn parse_definition(
     }
 }
 
-trait Consumable: Node<String> {
+pub(crate) trait Consumable: Node<String> {
     fn is_consumable(c: char) -> bool;
 }
 
@@ -195,6 +298,20 @@ impl Consumable for Whitespace {
     }
 }
 
+pub(crate) fn validate<T: Consumable>(
+    value: &str,
+    expected: &'static str,
+) -> Result<T, SyntaxError> {
+    if value.is_empty() {
+        return Err(SyntaxError::UnexpectedEOL { expected });
+    }
+
+    match value.chars().find(|c| !T::is_consumable(*c)) {
+        Some(token) => Err(SyntaxError::UnexpectedToken { token, expected }),
+        None => Ok(T::new(value.to_string())),
+    }
+}
+
 fn consume<T: Consumable>(first: char, chars: &mut Chars) -> (T, Option<char>) {
     let mut output = String::from(first);
 
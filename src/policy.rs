@@ -0,0 +1,293 @@
+use crate::{scanner, version, ToolVersions};
+use std::{cmp::Ordering, fmt, fs, io, path::Path};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operator {
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Constraint {
+    pub operator: Operator,
+    pub version: String,
+}
+
+impl Constraint {
+    fn parse(s: &str) -> Option<Self> {
+        let (operator, version) = if let Some(version) = s.strip_prefix(">=") {
+            (Operator::GreaterOrEqual, version)
+        } else if let Some(version) = s.strip_prefix("<=") {
+            (Operator::LessOrEqual, version)
+        } else if let Some(version) = s.strip_prefix('>') {
+            (Operator::Greater, version)
+        } else if let Some(version) = s.strip_prefix('<') {
+            (Operator::Less, version)
+        } else if let Some(version) = s.strip_prefix('=') {
+            (Operator::Equal, version)
+        } else {
+            (Operator::Equal, s)
+        };
+
+        if version.is_empty() {
+            return None;
+        }
+
+        Some(Constraint {
+            operator,
+            version: version.to_string(),
+        })
+    }
+
+    pub fn matches(&self, version: &str) -> bool {
+        let ordering = version::compare(version, &self.version);
+
+        match self.operator {
+            Operator::Equal => ordering == Ordering::Equal,
+            Operator::Greater => ordering == Ordering::Greater,
+            Operator::GreaterOrEqual => ordering != Ordering::Less,
+            Operator::Less => ordering == Ordering::Less,
+            Operator::LessOrEqual => ordering != Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self.operator {
+            Operator::Equal => "=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+        };
+
+        write!(f, "{}{}", operator, self.version)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Rule {
+    Require(String),
+    Forbid(String),
+    Range {
+        tool_name: String,
+        constraints: Vec<Constraint>,
+    },
+    Ban {
+        tool_name: String,
+        pattern: String,
+    },
+    ForbidSystem(Option<String>),
+    ForbidLatest(Option<String>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PolicyError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Violation {
+    pub rule: Rule,
+    pub tool_name: String,
+    pub version: Option<String>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Policy {
+    pub rules: Vec<Rule>,
+}
+
+impl Policy {
+    pub fn parse(input: &str) -> Result<Self, PolicyError> {
+        let mut rules = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let line = match line.split_once('#') {
+                Some((line, _)) => line,
+                None => line,
+            };
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            let error = |message: &str| PolicyError {
+                line: i + 1,
+                message: message.to_string(),
+            };
+
+            let rule = match words.as_slice() {
+                [] => continue,
+                ["require", tool_name] => Rule::Require(tool_name.to_string()),
+                ["forbid", tool_name] => Rule::Forbid(tool_name.to_string()),
+                ["range", tool_name, constraints @ ..] if !constraints.is_empty() => Rule::Range {
+                    tool_name: tool_name.to_string(),
+                    constraints: constraints
+                        .iter()
+                        .map(|constraint| Constraint::parse(constraint))
+                        .collect::<Option<Vec<Constraint>>>()
+                        .ok_or_else(|| error("invalid version constraint"))?,
+                },
+                ["ban", tool_name, pattern] => Rule::Ban {
+                    tool_name: tool_name.to_string(),
+                    pattern: pattern.to_string(),
+                },
+                ["forbid-system"] => Rule::ForbidSystem(None),
+                ["forbid-system", tool_name] => Rule::ForbidSystem(Some(tool_name.to_string())),
+                ["forbid-latest"] => Rule::ForbidLatest(None),
+                ["forbid-latest", tool_name] => Rule::ForbidLatest(Some(tool_name.to_string())),
+                [directive, ..] => {
+                    return Err(error(&format!("invalid rule '{}'", directive)));
+                }
+            };
+
+            rules.push(rule);
+        }
+
+        Ok(Policy { rules })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Policy::parse(&fs::read_to_string(path)?)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))
+    }
+
+    pub fn evaluate(&self, tools: &ToolVersions) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        for rule in &self.rules {
+            match rule {
+                Rule::Require(tool_name) => {
+                    if tools.versions(tool_name).is_none() {
+                        violations.push(Violation {
+                            rule: rule.clone(),
+                            tool_name: tool_name.clone(),
+                            version: None,
+                            line: None,
+                            message: format!("{} must be pinned", tool_name),
+                        });
+                    }
+                }
+                Rule::Forbid(tool_name) => {
+                    if tools.versions(tool_name).is_some() {
+                        violations.push(Violation {
+                            rule: rule.clone(),
+                            tool_name: tool_name.clone(),
+                            version: None,
+                            line: tools.line_number(tool_name),
+                            message: format!("{} is forbidden", tool_name),
+                        });
+                    }
+                }
+                Rule::Range {
+                    tool_name,
+                    constraints,
+                } => {
+                    for version in versions(tools, tool_name) {
+                        if !is_concrete(&version) {
+                            continue;
+                        }
+
+                        if let Some(constraint) = constraints
+                            .iter()
+                            .find(|constraint| !constraint.matches(&version))
+                        {
+                            violations.push(Violation {
+                                rule: rule.clone(),
+                                tool_name: tool_name.clone(),
+                                line: tools.line_number(tool_name),
+                                message: format!(
+                                    "{} {} does not satisfy {}",
+                                    tool_name, version, constraint
+                                ),
+                                version: Some(version),
+                            });
+                        }
+                    }
+                }
+                Rule::Ban { tool_name, pattern } => {
+                    for version in versions(tools, tool_name) {
+                        if scanner::glob_match(pattern, &version) {
+                            violations.push(Violation {
+                                rule: rule.clone(),
+                                tool_name: tool_name.clone(),
+                                line: tools.line_number(tool_name),
+                                message: format!(
+                                    "{} {} is banned ({})",
+                                    tool_name, version, pattern
+                                ),
+                                version: Some(version),
+                            });
+                        }
+                    }
+                }
+                Rule::ForbidSystem(tool_name) => prohibit(
+                    tools,
+                    rule,
+                    tool_name.as_ref(),
+                    &mut violations,
+                    |version| version == "system",
+                ),
+                Rule::ForbidLatest(tool_name) => prohibit(
+                    tools,
+                    rule,
+                    tool_name.as_ref(),
+                    &mut violations,
+                    |version| version == "latest" || version.starts_with("latest:"),
+                ),
+            }
+        }
+
+        violations
+    }
+}
+
+fn versions(tools: &ToolVersions, tool_name: &str) -> Vec<String> {
+    tools.versions(tool_name).unwrap_or_default()
+}
+
+fn is_concrete(version: &str) -> bool {
+    !(version == "system"
+        || version == "latest"
+        || version.starts_with("latest:")
+        || version.starts_with("path:")
+        || version.starts_with("ref:"))
+}
+
+fn prohibit<F: Fn(&str) -> bool>(
+    tools: &ToolVersions,
+    rule: &Rule,
+    tool_name: Option<&String>,
+    violations: &mut Vec<Violation>,
+    is_prohibited: F,
+) {
+    let tool_names = match tool_name {
+        Some(tool_name) => vec![tool_name.clone()],
+        None => tools.tools(),
+    };
+
+    for tool_name in tool_names {
+        for version in versions(tools, &tool_name) {
+            if is_prohibited(&version) {
+                violations.push(Violation {
+                    rule: rule.clone(),
+                    line: tools.line_number(&tool_name),
+                    message: format!("{} must not use {}", tool_name, version),
+                    tool_name: tool_name.clone(),
+                    version: Some(version),
+                });
+            }
+        }
+    }
+}
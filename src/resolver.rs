@@ -0,0 +1,62 @@
+use crate::{
+    inventory::{Installs, Status},
+    ToolVersions,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Skipped {
+    pub version: String,
+    pub reason: Status,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Resolution {
+    pub tool_name: String,
+    pub selected: Option<(String, Status)>,
+    pub skipped: Vec<Skipped>,
+}
+
+impl Resolution {
+    pub fn version(&self) -> Option<&String> {
+        self.selected.as_ref().map(|(version, _)| version)
+    }
+}
+
+pub fn resolve<I: Installs>(tools: &ToolVersions, installs: &I) -> Vec<Resolution> {
+    tools
+        .tools()
+        .iter()
+        .filter_map(|tool_name| resolve_tool(tools, tool_name, installs))
+        .collect()
+}
+
+pub fn resolve_tool<I: Installs>(
+    tools: &ToolVersions,
+    tool_name: &str,
+    installs: &I,
+) -> Option<Resolution> {
+    let versions = tools.versions(tool_name)?;
+
+    let mut skipped = vec![];
+    let mut selected = None;
+
+    for version in versions {
+        let status = installs.status(tool_name, &version);
+
+        if status.is_usable() {
+            selected = Some((version, status));
+            break;
+        }
+
+        skipped.push(Skipped {
+            version,
+            reason: status,
+        });
+    }
+
+    Some(Resolution {
+        tool_name: tool_name.to_string(),
+        selected,
+        skipped,
+    })
+}
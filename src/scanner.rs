@@ -0,0 +1,278 @@
+use crate::{
+    ast::{Line, SyntaxError},
+    files, ToolVersions,
+};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+};
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub filename: String,
+    pub max_depth: Option<usize>,
+    pub ignore: Vec<String>,
+    pub read_gitignore: bool,
+    pub threads: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            filename: files::DEFAULT_FILENAME.to_string(),
+            max_depth: None,
+            ignore: vec![".git/".to_string()],
+            read_gitignore: true,
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScanError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Syntax {
+        path: PathBuf,
+        line: usize,
+        error: SyntaxError,
+    },
+}
+
+pub struct Scan {
+    pub files: BTreeMap<PathBuf, ToolVersions>,
+    pub errors: Vec<ScanError>,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    base: PathBuf,
+    glob: String,
+    anchored: bool,
+    directory_only: bool,
+    negated: bool,
+}
+
+impl Pattern {
+    fn parse(base: &Path, line: &str) -> Option<Self> {
+        let line = line.trim_end();
+
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+
+        let (directory_only, line) = match line.strip_suffix('/') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+
+        let anchored = line.contains('/');
+        let glob = line.trim_start_matches('/').to_string();
+
+        if glob.is_empty() {
+            return None;
+        }
+
+        Some(Pattern {
+            base: base.to_path_buf(),
+            glob,
+            anchored,
+            directory_only,
+            negated,
+        })
+    }
+
+    fn matches(&self, path: &Path, is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+
+        let Ok(relative) = path.strip_prefix(&self.base) else {
+            return false;
+        };
+
+        if self.anchored {
+            let relative = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            glob_match(&self.glob, &relative)
+        } else {
+            path.file_name()
+                .is_some_and(|name| glob_match(&self.glob, &name.to_string_lossy()))
+        }
+    }
+}
+
+pub fn scan<P: AsRef<Path>>(root: P, options: &Options) -> Scan {
+    let root = root.as_ref();
+    let patterns: Vec<Pattern> = options
+        .ignore
+        .iter()
+        .filter_map(|pattern| Pattern::parse(root, pattern))
+        .collect();
+
+    let mut paths = vec![];
+    let mut errors = vec![];
+
+    walk(root, 0, &patterns, options, &mut paths, &mut errors);
+
+    let results = Mutex::new(vec![]);
+    let threads = options.threads.max(1);
+    let chunk_size = paths.len().div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        for chunk in paths.chunks(chunk_size) {
+            let results = &results;
+
+            scope.spawn(move || {
+                let parsed: Vec<(PathBuf, io::Result<ToolVersions>)> = chunk
+                    .iter()
+                    .map(|path| (path.clone(), ToolVersions::from_file(path)))
+                    .collect();
+
+                results
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .extend(parsed);
+            });
+        }
+    });
+
+    let mut files = BTreeMap::new();
+
+    for (path, result) in results
+        .into_inner()
+        .unwrap_or_else(|error| error.into_inner())
+    {
+        match result {
+            Ok(tools) => {
+                files.insert(path, tools);
+            }
+            Err(error) => errors.push(ScanError::Io { path, error }),
+        }
+    }
+
+    for (path, tools) in &files {
+        for (number, line) in tools.document.ast().numbered_lines() {
+            if let Line::Invalid { error, .. } | Line::Conflict { error, .. } = line {
+                errors.push(ScanError::Syntax {
+                    path: path.clone(),
+                    line: number,
+                    error: error.clone(),
+                });
+            }
+        }
+    }
+
+    Scan { files, errors }
+}
+
+fn walk(
+    dir: &Path,
+    depth: usize,
+    patterns: &[Pattern],
+    options: &Options,
+    paths: &mut Vec<PathBuf>,
+    errors: &mut Vec<ScanError>,
+) {
+    let mut patterns = patterns.to_vec();
+
+    if options.read_gitignore {
+        match fs::read_to_string(dir.join(".gitignore")) {
+            Ok(content) => {
+                patterns.extend(content.lines().filter_map(|line| Pattern::parse(dir, line)))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => errors.push(ScanError::Io {
+                path: dir.join(".gitignore"),
+                error,
+            }),
+        }
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) => {
+            errors.push(ScanError::Io {
+                path: dir.to_path_buf(),
+                error,
+            });
+            return;
+        }
+    };
+
+    let mut entries: Vec<(PathBuf, bool)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let is_dir = entry.file_type().ok()?.is_dir();
+            Some((entry.path(), is_dir))
+        })
+        .collect();
+    entries.sort();
+
+    for (path, is_dir) in entries {
+        if is_ignored(&patterns, &path, is_dir) {
+            continue;
+        }
+
+        if is_dir {
+            if options.max_depth.is_none_or(|max_depth| depth < max_depth) {
+                walk(&path, depth + 1, &patterns, options, paths, errors);
+            }
+        } else if path
+            .file_name()
+            .is_some_and(|name| *name == *options.filename)
+            && path.is_file()
+        {
+            paths.push(path);
+        }
+    }
+}
+
+fn is_ignored(patterns: &[Pattern], path: &Path, is_dir: bool) -> bool {
+    patterns
+        .iter()
+        .rev()
+        .find(|pattern| pattern.matches(path, is_dir))
+        .is_some_and(|pattern| !pattern.negated)
+}
+
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    glob_match_from(&pattern, &text)
+}
+
+fn glob_match_from(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            glob_match_from(rest, text)
+                || (0..text.len())
+                    .filter(|&i| text[i] == '/')
+                    .any(|i| glob_match_from(rest, &text[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=text.len()).any(|i| glob_match_from(rest, &text[i..])),
+        ['*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != '/')
+            .any(|i| glob_match_from(rest, &text[i..])),
+        ['?', rest @ ..] => {
+            matches!(text.first(), Some(c) if *c != '/') && glob_match_from(rest, &text[1..])
+        }
+        [c, rest @ ..] => text.first() == Some(c) && glob_match_from(rest, &text[1..]),
+    }
+}
@@ -0,0 +1,101 @@
+use crate::ToolVersions;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Posix,
+    Dotenv,
+    Makefile,
+}
+
+pub fn variable_name(tool_name: &str) -> String {
+    let name: String = tool_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("ASDF_{}_VERSION", name)
+}
+
+pub fn exports(tools: &ToolVersions, shell: Shell) -> String {
+    tools
+        .tools()
+        .iter()
+        .filter_map(|tool_name| {
+            let versions = tools.versions(tool_name)?;
+            Some(export(
+                &variable_name(tool_name),
+                &versions.join(" "),
+                shell,
+            ))
+        })
+        .collect()
+}
+
+pub fn export(name: &str, value: &str, shell: Shell) -> String {
+    match shell {
+        Shell::Bash | Shell::Zsh | Shell::Posix => {
+            format!("export {}={}\n", name, quote_posix(value))
+        }
+        Shell::Fish => format!("set -gx {} {}\n", name, quote_fish(value)),
+        Shell::Dotenv => format!("{}={}\n", name, quote_dotenv(value)),
+        Shell::Makefile => format!("export {} := {}\n", name, escape_makefile(value)),
+    }
+}
+
+fn is_safe(value: &str) -> bool {
+    !value.is_empty()
+        && value.chars().all(|c| {
+            c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+' | ':' | '/' | '@' | ',')
+        })
+}
+
+fn quote_posix(value: &str) -> String {
+    if is_safe(value) {
+        return value.to_string();
+    }
+
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn quote_fish(value: &str) -> String {
+    if is_safe(value) {
+        return value.to_string();
+    }
+
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn quote_dotenv(value: &str) -> String {
+    if is_safe(value) {
+        return value.to_string();
+    }
+
+    let mut s = String::from('"');
+
+    for c in value.chars() {
+        match c {
+            '\\' | '"' | '$' | '`' => {
+                s.push('\\');
+                s.push(c);
+            }
+            '\n' => s.push_str("\\n"),
+            c => s.push(c),
+        }
+    }
+
+    s.push('"');
+    s
+}
+
+fn escape_makefile(value: &str) -> String {
+    value.replace('$', "$$").replace('#', "\\#")
+}
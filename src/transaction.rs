@@ -0,0 +1,231 @@
+use crate::{
+    ast::{Identifier, Node, SyntaxError, Unparsed, Version},
+    ToolVersions,
+};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Operation {
+    Set {
+        tool_name: String,
+        versions: Vec<String>,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+    Remove(String),
+    Comment {
+        tool_name: String,
+        comment: Option<String>,
+    },
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Set {
+                tool_name,
+                versions,
+            } => {
+                write!(f, "set {}", tool_name)?;
+
+                for version in versions {
+                    write!(f, " {}", version)?;
+                }
+
+                Ok(())
+            }
+            Operation::Rename { from, to } => write!(f, "rename {} to {}", from, to),
+            Operation::Remove(tool_name) => write!(f, "remove {}", tool_name),
+            Operation::Comment { tool_name, .. } => write!(f, "comment {}", tool_name),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Reason {
+    Syntax(SyntaxError),
+    EmptyVersions,
+    NotFound(String),
+    AlreadyExists(String),
+    Conflicted(String),
+    MultilineComment,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::Syntax(error) => write!(f, "{}", error),
+            Reason::EmptyVersions => write!(f, "empty version list, use remove instead"),
+            Reason::NotFound(tool_name) => write!(f, "{} is not pinned", tool_name),
+            Reason::AlreadyExists(tool_name) => write!(f, "{} is already pinned", tool_name),
+            Reason::Conflicted(tool_name) => {
+                write!(f, "{} is inside an unresolved merge conflict", tool_name)
+            }
+            Reason::MultilineComment => write!(f, "comment must be a single line"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransactionError {
+    pub index: usize,
+    pub operation: Operation,
+    pub reason: Reason,
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "operation {} ({}) failed: {}",
+            self.index + 1,
+            self.operation,
+            self.reason
+        )
+    }
+}
+
+pub struct Transaction<'a> {
+    tools: &'a mut ToolVersions,
+    operations: Vec<Operation>,
+}
+
+impl<'a> Transaction<'a> {
+    pub fn new(tools: &'a mut ToolVersions) -> Self {
+        Transaction {
+            tools,
+            operations: vec![],
+        }
+    }
+
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    pub fn set(mut self, tool_name: &str, versions: Vec<&str>) -> Self {
+        self.operations.push(Operation::Set {
+            tool_name: tool_name.to_string(),
+            versions: versions.iter().map(|version| version.to_string()).collect(),
+        });
+        self
+    }
+
+    pub fn rename(mut self, from: &str, to: &str) -> Self {
+        self.operations.push(Operation::Rename {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        self
+    }
+
+    pub fn remove(mut self, tool_name: &str) -> Self {
+        self.operations
+            .push(Operation::Remove(tool_name.to_string()));
+        self
+    }
+
+    pub fn comment(mut self, tool_name: &str, comment: Option<&str>) -> Self {
+        self.operations.push(Operation::Comment {
+            tool_name: tool_name.to_string(),
+            comment: comment.map(String::from),
+        });
+        self
+    }
+
+    pub fn commit(self) -> Result<(), TransactionError> {
+        let mut document = self.tools.document.clone();
+
+        for (index, operation) in self.operations.iter().enumerate() {
+            let error = |reason| TransactionError {
+                index,
+                operation: operation.clone(),
+                reason,
+            };
+            let identifier = |name: &str| {
+                let identifier = Identifier::try_new(name).map_err(|e| error(Reason::Syntax(e)))?;
+
+                if document.is_conflicted(&identifier) {
+                    return Err(error(Reason::Conflicted(name.to_string())));
+                }
+
+                Ok(identifier)
+            };
+
+            match operation {
+                Operation::Set {
+                    tool_name,
+                    versions,
+                } => {
+                    let tool_name = identifier(tool_name)?;
+
+                    if versions.is_empty() {
+                        return Err(error(Reason::EmptyVersions));
+                    }
+
+                    let versions = versions
+                        .iter()
+                        .map(|version| Version::try_new(version))
+                        .collect::<Result<Vec<Version>, SyntaxError>>()
+                        .map_err(|e| error(Reason::Syntax(e)))?;
+
+                    document
+                        .set_versions(tool_name, versions)
+                        .map_err(|e| error(Reason::Syntax(e)))?;
+                }
+                Operation::Rename { from, to } => {
+                    let from = identifier(from)?;
+                    let to = identifier(to)?;
+
+                    if document.line_index(&from).is_none() {
+                        return Err(error(Reason::NotFound(from.value().clone())));
+                    }
+
+                    if document.line_index(&to).is_some() {
+                        return Err(error(Reason::AlreadyExists(to.value().clone())));
+                    }
+
+                    document
+                        .rename(&from, to)
+                        .map_err(|e| error(Reason::Syntax(e)))?;
+                }
+                Operation::Remove(tool_name) => {
+                    let tool_name = identifier(tool_name)?;
+
+                    if document.line_index(&tool_name).is_none() {
+                        return Err(error(Reason::NotFound(tool_name.value().clone())));
+                    }
+
+                    document
+                        .set_versions(tool_name, vec![])
+                        .map_err(|e| error(Reason::Syntax(e)))?;
+                }
+                Operation::Comment { tool_name, comment } => {
+                    let tool_name = identifier(tool_name)?;
+
+                    if comment
+                        .as_ref()
+                        .is_some_and(|comment| comment.contains(['\n', '\r']))
+                    {
+                        return Err(error(Reason::MultilineComment));
+                    }
+
+                    let comment = comment
+                        .as_ref()
+                        .map(|comment| Unparsed::new(format!(" {}", comment)));
+
+                    if !document
+                        .set_comment(&tool_name, comment)
+                        .map_err(|e| error(Reason::Syntax(e)))?
+                    {
+                        return Err(error(Reason::NotFound(tool_name.value().clone())));
+                    }
+                }
+            }
+        }
+
+        self.tools.document = document;
+        Ok(())
+    }
+}
@@ -1,85 +1,155 @@
-use crate::ast::{Identifier, Line, Node, Version, Versions, Whitespace, AST};
+use crate::{
+    ast::{Identifier, Line, Node, SyntaxError, Version, Versions, AST},
+    document::Document,
+    merge, version,
+};
+use std::cmp::Ordering;
 
-pub fn set_versions(ast: &AST, tool_name: Identifier, versions: Vec<Version>) -> AST {
-    let versions: Vec<(Whitespace, Version)> = versions
-        .iter()
-        .map(|version| (Whitespace::new(" ".to_string()), version.clone()))
-        .collect();
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ConflictResolution {
+    Ours,
+    Theirs,
+    HighestVersion,
+}
 
-    if ast.lines.len() == 0 {
-        if versions.len() == 0 {
-            return AST { lines: vec![] };
-        }
+pub fn set_versions(
+    ast: &AST,
+    tool_name: Identifier,
+    versions: Vec<Version>,
+) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.set_versions(tool_name, versions))
+}
 
-        return AST {
-            lines: vec![Line::ToolDefinition {
-                name: tool_name,
-                versions: Versions::new(versions),
-                whitespace: None,
-                comment: None,
-            }],
-        };
-    }
+pub fn append_version(
+    ast: &AST,
+    tool_name: Identifier,
+    version: Version,
+) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.append_version(tool_name, version))
+}
 
-    if !ast.lines.iter().any(|line| match line {
-        Line::ToolDefinition { name, .. } if *name == tool_name => true,
-        _ => false,
-    }) {
-        let mut lines = ast.lines.clone();
+pub fn prepend_version(
+    ast: &AST,
+    tool_name: Identifier,
+    version: Version,
+) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.prepend_version(tool_name, version))
+}
 
-        if versions.len() == 0 {
-            return AST { lines };
-        }
+pub fn remove_version(
+    ast: &AST,
+    tool_name: Identifier,
+    version: Version,
+) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.remove_version(tool_name, version))
+}
 
-        lines.push(Line::ToolDefinition {
-            name: tool_name,
-            versions: Versions::new(versions),
-            whitespace: None,
-            comment: None,
-        });
+pub fn promote_version(
+    ast: &AST,
+    tool_name: Identifier,
+    version: Version,
+) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.promote_version(tool_name, version))
+}
 
-        return AST { lines };
-    }
+pub fn dedupe_versions(ast: &AST, tool_name: Identifier) -> Result<AST, SyntaxError> {
+    edit(ast, |document| document.dedupe_versions(tool_name))
+}
+
+fn edit<F: FnOnce(&mut Document) -> Result<(), SyntaxError>>(
+    ast: &AST,
+    edit: F,
+) -> Result<AST, SyntaxError> {
+    let mut document = Document::new(AST {
+        lines: ast.lines.clone(),
+    });
+    edit(&mut document)?;
+    Ok(document.into_ast())
+}
 
+pub fn resolve_conflicts(ast: &AST, resolution: ConflictResolution) -> AST {
     AST {
         lines: ast
             .lines
             .iter()
-            .filter_map(|line| match line {
-                Line::ToolDefinition {
-                    name,
-                    whitespace,
-                    comment,
-                    versions: old_versions,
-                } if *name == tool_name => {
-                    if versions.len() == 0 {
-                        return None;
-                    }
-
-                    let new_versions = versions
-                        .iter()
-                        .enumerate()
-                        .map(|(i, (_, version))| {
-                            let left_padding =
-                                if let Some((old_padding, _)) = old_versions.value().get(i) {
-                                    old_padding.clone()
-                                } else {
-                                    Whitespace::new(" ".to_string())
-                                };
-
-                            (left_padding, version.clone())
-                        })
-                        .collect();
-
-                    Some(Line::ToolDefinition {
-                        name: name.clone(),
-                        versions: Versions::new(new_versions),
-                        whitespace: whitespace.clone(),
-                        comment: comment.clone(),
-                    })
-                }
-                line => Some(line.clone()),
+            .flat_map(|line| match line {
+                Line::Conflict { ours, theirs, .. } => match resolution {
+                    ConflictResolution::Ours => ours.clone(),
+                    ConflictResolution::Theirs => theirs.clone(),
+                    ConflictResolution::HighestVersion => highest_versions(ours, theirs),
+                },
+                line => vec![line.clone()],
             })
             .collect(),
     }
 }
+
+fn highest_versions(ours: &[Line], theirs: &[Line]) -> Vec<Line> {
+    let key = |line| line_name(line).ok_or(line);
+    let ours_keys: Vec<Result<&Identifier, &Line>> = ours.iter().map(key).collect();
+    let theirs_keys: Vec<Result<&Identifier, &Line>> = theirs.iter().map(key).collect();
+    let matches = merge::matches(&ours_keys, &theirs_keys);
+
+    let winner = |line: &Line| {
+        if let Line::ToolDefinition { name, versions, .. } = line {
+            if let Some(
+                their_line @ Line::ToolDefinition {
+                    versions: their_versions,
+                    ..
+                },
+            ) = theirs.iter().find(|line| line_name(line) == Some(name))
+            {
+                if compare_versions(their_versions, versions) == Ordering::Greater {
+                    return their_line.clone();
+                }
+            }
+        }
+
+        line.clone()
+    };
+
+    let theirs_only = |line: &Line| match line_name(line) {
+        Some(name) => !ours.iter().any(|line| line_name(line) == Some(name)),
+        None => true,
+    };
+
+    let mut lines = vec![];
+    let mut j = 0;
+
+    for (i, line) in ours.iter().enumerate() {
+        if let Some(matched) = matches[i] {
+            lines.extend(
+                theirs[j..matched]
+                    .iter()
+                    .filter(|line| theirs_only(line))
+                    .cloned(),
+            );
+            j = matched + 1;
+        }
+
+        lines.push(winner(line));
+    }
+
+    lines.extend(theirs[j..].iter().filter(|line| theirs_only(line)).cloned());
+
+    lines
+}
+
+fn line_name(line: &Line) -> Option<&Identifier> {
+    match line {
+        Line::ToolDefinition { name, .. } => Some(name),
+        _ => None,
+    }
+}
+
+fn compare_versions(left: &Versions, right: &Versions) -> Ordering {
+    for (left, right) in left.value().iter().zip(right.value()) {
+        let ordering = version::compare(left.1.value(), right.1.value());
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    left.value().len().cmp(&right.value().len())
+}
@@ -0,0 +1,174 @@
+use crate::{
+    ast::{Identifier, Node, SyntaxError, Version},
+    version, ToolVersions,
+};
+use std::{
+    cmp::Ordering,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub trait VersionIndex {
+    fn available_versions(&self, tool_name: &str) -> io::Result<Vec<String>>;
+
+    fn aliases(&self, _tool_name: &str) -> io::Result<Vec<(String, String)>> {
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileIndex {
+    dir: PathBuf,
+}
+
+impl FileIndex {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        FileIndex {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn entries(&self, tool_name: &str) -> io::Result<Vec<String>> {
+        match fs::read_to_string(self.dir.join(tool_name)) {
+            Ok(contents) => Ok(contents.split_whitespace().map(String::from).collect()),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl VersionIndex for FileIndex {
+    fn available_versions(&self, tool_name: &str) -> io::Result<Vec<String>> {
+        Ok(self
+            .entries(tool_name)?
+            .into_iter()
+            .filter(|entry| !entry.contains('='))
+            .collect())
+    }
+
+    fn aliases(&self, tool_name: &str) -> io::Result<Vec<(String, String)>> {
+        Ok(self
+            .entries(tool_name)?
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .map(|(alias, version)| (alias.to_string(), version.to_string()))
+            .collect())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Strategy {
+    Patch,
+    Minor,
+    Major,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Upgrade {
+    pub tool_name: String,
+    pub line: Option<usize>,
+    pub from: String,
+    pub to: String,
+}
+
+pub fn plan<I: VersionIndex>(
+    tools: &ToolVersions,
+    index: &I,
+    strategy: Strategy,
+) -> io::Result<Vec<Upgrade>> {
+    let mut upgrades = vec![];
+
+    for tool_name in tools.tools() {
+        let pinned = tools.versions(&tool_name).unwrap_or_default();
+
+        if pinned.iter().all(|version| numeric(version).is_none()) {
+            continue;
+        }
+
+        let available = index.available_versions(&tool_name)?;
+
+        for version in pinned {
+            if let Some(to) = latest(&version, &available, strategy) {
+                upgrades.push(Upgrade {
+                    line: tools.line_number(&tool_name),
+                    tool_name: tool_name.clone(),
+                    from: version,
+                    to,
+                });
+            }
+        }
+    }
+
+    Ok(upgrades)
+}
+
+pub fn apply(tools: &mut ToolVersions, upgrades: &[Upgrade]) -> Result<(), SyntaxError> {
+    for upgrade in upgrades {
+        let tool_name = Identifier::try_new(&upgrade.tool_name)?;
+
+        if tools.document.is_conflicted(&tool_name) {
+            return Err(SyntaxError::ConflictMarker);
+        }
+
+        let Some(versions) = tools.versions(&upgrade.tool_name) else {
+            continue;
+        };
+
+        let to = Version::try_new(&upgrade.to)?;
+
+        tools.document.set_versions(
+            tool_name,
+            versions
+                .into_iter()
+                .map(|version| {
+                    if version == upgrade.from {
+                        to.clone()
+                    } else {
+                        Version::new(version)
+                    }
+                })
+                .collect(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn latest(version: &str, available: &[String], strategy: Strategy) -> Option<String> {
+    let current = numeric(version)?;
+    let fixed = match strategy {
+        Strategy::Patch => 2,
+        Strategy::Minor => 1,
+        Strategy::Major => 0,
+    };
+
+    if current.len() <= fixed {
+        return None;
+    }
+
+    available
+        .iter()
+        .filter(|candidate| {
+            numeric(candidate).is_some_and(|parts| {
+                parts.len() >= current.len() && parts[..fixed] == current[..fixed]
+            })
+        })
+        .map(|candidate| {
+            candidate
+                .split('.')
+                .take(current.len())
+                .collect::<Vec<&str>>()
+                .join(".")
+        })
+        .filter(|candidate| version::compare(candidate, version) == Ordering::Greater)
+        .max_by(|left, right| version::compare(left, right))
+}
+
+fn numeric(version: &str) -> Option<Vec<u64>> {
+    version
+        .strip_prefix('v')
+        .unwrap_or(version)
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment<'a> {
+    Number(u64),
+    Text(&'a str),
+}
+
+fn segments(version: &str) -> Vec<Segment<'_>> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let mut segments = vec![];
+
+    for part in version.split(['.', '-', '_', '+']) {
+        let mut start = 0;
+
+        for (i, c) in part.char_indices().skip(1) {
+            let previous = part[..i].chars().next_back();
+
+            if previous.map(|p| p.is_ascii_digit()) != Some(c.is_ascii_digit()) {
+                segments.push(segment(&part[start..i]));
+                start = i;
+            }
+        }
+
+        if start < part.len() {
+            segments.push(segment(&part[start..]));
+        }
+    }
+
+    segments
+}
+
+fn segment(s: &str) -> Segment<'_> {
+    match s.parse() {
+        Ok(number) => Segment::Number(number),
+        Err(_) => Segment::Text(s),
+    }
+}
+
+fn split_pre_release(version: &str) -> (&str, Option<&str>) {
+    let version = version.strip_prefix('v').unwrap_or(version);
+
+    match version.split_once('-') {
+        Some((release, pre_release)) if release.starts_with(|c: char| c.is_ascii_digit()) => {
+            (release, Some(pre_release))
+        }
+        _ => (version, None),
+    }
+}
+
+pub fn compare(left: &str, right: &str) -> Ordering {
+    compare_segments(&segments(left), &segments(right))
+}
+
+pub fn compare_semver(left: &str, right: &str) -> Ordering {
+    let (left_release, left_pre_release) = split_pre_release(left);
+    let (right_release, right_pre_release) = split_pre_release(right);
+
+    match compare_segments(&segments(left_release), &segments(right_release)) {
+        Ordering::Equal => match (left_pre_release, right_pre_release) {
+            (Some(left), Some(right)) => compare_segments(&segments(left), &segments(right)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+        ordering => ordering,
+    }
+}
+
+fn compare_segments(left: &[Segment], right: &[Segment]) -> Ordering {
+    for i in 0..left.len().max(right.len()) {
+        let ordering = match (left.get(i), right.get(i)) {
+            (Some(Segment::Number(l)), Some(Segment::Number(r))) => l.cmp(r),
+            (Some(Segment::Text(l)), Some(Segment::Text(r))) => l.cmp(r),
+            (Some(Segment::Number(_)), Some(Segment::Text(_))) => Ordering::Greater,
+            (Some(Segment::Text(_)), Some(Segment::Number(_))) => Ordering::Less,
+            (Some(Segment::Number(_)), None) => Ordering::Greater,
+            (Some(Segment::Text(_)), None) => Ordering::Less,
+            (None, Some(Segment::Number(_))) => Ordering::Less,
+            (None, Some(Segment::Text(_))) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
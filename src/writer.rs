@@ -6,17 +6,21 @@ pub fn write_file<P: AsRef<Path>>(ast: &AST, path: P) -> io::Result<()> {
 }
 
 pub fn write(ast: &AST) -> String {
-    let lines: Vec<String> = ast.lines.iter().map(|line| line.to_string()).collect();
+    if ast.lines.is_empty() {
+        return String::new();
+    }
+
+    let lines: Vec<String> = ast.lines.iter().map(|line| line.to_source()).collect();
 
-    let mut result = String::from(lines.join("\n"));
+    let mut result = lines.join("\n");
 
-    result.push_str("\n");
+    result.push('\n');
 
     result
 }
 
 impl Line {
-    fn to_string(&self) -> String {
+    pub(crate) fn to_source(&self) -> String {
         match self {
             Line::ToolDefinition {
                 name,
@@ -44,7 +48,7 @@ impl Line {
                 }
 
                 if let Some(comment) = comment {
-                    s.push_str("#");
+                    s.push('#');
                     s.push_str(comment.value());
                 }
 
@@ -61,13 +65,38 @@ impl Line {
                 }
 
                 if let Some(comment) = comment {
-                    s.push_str("#");
+                    s.push('#');
                     s.push_str(comment.value());
                 }
 
                 s
             }
             Line::Invalid { unparsed, .. } => unparsed.value().clone(),
+            Line::Conflict {
+                ours_marker,
+                ours,
+                base_marker,
+                base,
+                separator,
+                theirs,
+                theirs_marker,
+                ..
+            } => {
+                let mut lines = vec![ours_marker.value().clone()];
+
+                lines.extend(ours.iter().map(|line| line.to_source()));
+
+                if let Some(base_marker) = base_marker {
+                    lines.push(base_marker.value().clone());
+                    lines.extend(base.iter().map(|line| line.to_source()));
+                }
+
+                lines.push(separator.value().clone());
+                lines.extend(theirs.iter().map(|line| line.to_source()));
+                lines.push(theirs_marker.value().clone());
+
+                lines.join("\n")
+            }
         }
     }
 }
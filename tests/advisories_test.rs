@@ -0,0 +1,78 @@
+use tool_versions::{
+    advisories::{self, Advisory, Database, Event, Finding, PackageMapping},
+    ToolVersions,
+};
+
+#[test]
+fn it_loads_local_mirror() {
+    let database = Database::load_dir("tests/__fixtures__/osv").unwrap();
+
+    assert_eq!(
+        database
+            .advisories
+            .iter()
+            .map(|advisory| advisory.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["GO-2023-2185", "GO-2024-0001", "GHSA-0000-node"]
+    );
+
+    let advisory = &database.advisories[0];
+
+    assert_eq!(advisory.aliases, vec!["CVE-2023-45283".to_string()]);
+    assert_eq!(
+        advisory.summary.as_deref(),
+        Some("Insecure parsing of Windows paths with a \\??\\ prefix in path/filepath")
+    );
+    assert_eq!(
+        advisory.affected[0].ranges[0],
+        vec![
+            Event::Introduced("0".to_string()),
+            Event::Fixed("1.20.11".to_string()),
+            Event::Introduced("1.21.0-0".to_string()),
+            Event::Fixed("1.21.4".to_string()),
+        ]
+    );
+    assert_eq!(database.advisories[1].affected[0].ranges.len(), 1);
+}
+
+#[test]
+fn it_reports_affected_versions() {
+    let database = Database::load_dir("tests/__fixtures__/osv").unwrap();
+    let tools = ToolVersions::from("# toolchain\ngolang 1.21.1 1.20.11 1.19.5\nnodejs 18.12.0\n");
+
+    assert_eq!(
+        advisories::audit(&tools, &database, &PackageMapping::default()),
+        vec![
+            Finding {
+                tool_name: "golang".to_string(),
+                version: "1.21.1".to_string(),
+                line: Some(2),
+                advisory_ids: vec!["GO-2023-2185".to_string(), "GO-2024-0001".to_string()],
+            },
+            Finding {
+                tool_name: "golang".to_string(),
+                version: "1.19.5".to_string(),
+                line: Some(2),
+                advisory_ids: vec!["GO-2023-2185".to_string(), "GO-2024-0001".to_string()],
+            },
+        ]
+    );
+
+    let mapping = PackageMapping::new().with("npm", "node", "nodejs");
+
+    assert_eq!(
+        advisories::audit(&tools, &database, &mapping),
+        vec![Finding {
+            tool_name: "nodejs".to_string(),
+            version: "18.12.0".to_string(),
+            line: Some(3),
+            advisory_ids: vec!["GHSA-0000-node".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn it_rejects_invalid_advisories() {
+    assert!(Advisory::parse("{\"affected\": []}").is_err());
+    assert!(Advisory::parse("{\"id\": \"X\",").is_err());
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tool_versions::asdfrc::{Concurrency, Config};
+
+#[test]
+fn it_parses_settings() {
+    let config = Config::parse(
+        "# asdf settings\nlegacy_version_file = yes\nuse_release_candidates=no # trailing\n  always_keep_download =   yes  \nconcurrency = 4\nplugin_repository_last_check_duration = never\ninvalid line\n= value\n",
+    );
+
+    assert!(config.legacy_version_file());
+    assert!(!config.use_release_candidates());
+    assert!(config.always_keep_download());
+    assert!(!config.disable_plugin_short_name_repository());
+    assert_eq!(config.concurrency(), Concurrency::Jobs(4));
+    assert_eq!(config.plugin_repository_last_check_duration(), None);
+    assert_eq!(config.get("use_release_candidates"), Some("no"));
+    assert_eq!(config.get("invalid line"), None);
+}
+
+#[test]
+fn it_uses_defaults() {
+    let config = Config::default();
+
+    assert!(!config.legacy_version_file());
+    assert!(!config.use_release_candidates());
+    assert!(!config.always_keep_download());
+    assert_eq!(config.concurrency(), Concurrency::Auto);
+    assert_eq!(config.plugin_repository_last_check_duration(), Some(60));
+}
+
+#[test]
+fn it_works_with_file() {
+    let dir = TempDir::new("_asdfrc");
+    let path = dir.join(".asdfrc");
+
+    fs::write(&path, "legacy_version_file = yes\n").unwrap();
+
+    assert!(Config::from_file(&path).unwrap().legacy_version_file());
+}
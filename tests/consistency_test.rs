@@ -0,0 +1,151 @@
+mod common;
+
+use common::TempDir;
+use std::{collections::BTreeMap, fs, path::PathBuf};
+use tool_versions::{
+    ast::SyntaxError,
+    consistency::{self, Exception, Inconsistency, Pin},
+    scanner::{self, Options},
+    ToolVersions,
+};
+
+fn files() -> BTreeMap<PathBuf, ToolVersions> {
+    BTreeMap::from([
+        (
+            PathBuf::from("/repo/.tool-versions"),
+            ToolVersions::from("nodejs 20.9\nruby 3.2\n"),
+        ),
+        (
+            PathBuf::from("/repo/api/.tool-versions"),
+            ToolVersions::from("# api\nnodejs  18.12  # old\nruby 3.2\n"),
+        ),
+        (
+            PathBuf::from("/repo/legacy/.tool-versions"),
+            ToolVersions::from("ruby 2.7\n"),
+        ),
+    ])
+}
+
+#[test]
+fn it_flags_inconsistent_pins() {
+    assert_eq!(
+        consistency::check(&files(), &[]),
+        vec![
+            Inconsistency {
+                tool_name: "nodejs".to_string(),
+                pins: vec![
+                    Pin {
+                        path: PathBuf::from("/repo/.tool-versions"),
+                        line: 1,
+                        versions: vec!["20.9".to_string()],
+                    },
+                    Pin {
+                        path: PathBuf::from("/repo/api/.tool-versions"),
+                        line: 2,
+                        versions: vec!["18.12".to_string()],
+                    },
+                ],
+            },
+            Inconsistency {
+                tool_name: "ruby".to_string(),
+                pins: vec![
+                    Pin {
+                        path: PathBuf::from("/repo/.tool-versions"),
+                        line: 2,
+                        versions: vec!["3.2".to_string()],
+                    },
+                    Pin {
+                        path: PathBuf::from("/repo/api/.tool-versions"),
+                        line: 3,
+                        versions: vec!["3.2".to_string()],
+                    },
+                    Pin {
+                        path: PathBuf::from("/repo/legacy/.tool-versions"),
+                        line: 1,
+                        versions: vec!["2.7".to_string()],
+                    },
+                ],
+            },
+        ]
+    );
+
+    let exceptions = [
+        Exception {
+            tool_name: "ruby".to_string(),
+            path: Some(PathBuf::from("/repo/legacy")),
+        },
+        Exception {
+            tool_name: "nodejs".to_string(),
+            path: None,
+        },
+    ];
+
+    assert_eq!(consistency::check(&files(), &exceptions), vec![]);
+}
+
+#[test]
+fn it_aligns_pins() {
+    let mut files = files();
+
+    let changed = consistency::align(
+        &mut files,
+        "nodejs",
+        vec!["20.9"],
+        &[Exception {
+            tool_name: "nodejs".to_string(),
+            path: Some(PathBuf::from("/repo/legacy")),
+        }],
+    )
+    .unwrap();
+
+    assert_eq!(changed, vec![PathBuf::from("/repo/api/.tool-versions")]);
+    assert_eq!(
+        files[&PathBuf::from("/repo/api/.tool-versions")].write(),
+        "# api\nnodejs  20.9  # old\nruby 3.2\n"
+    );
+    assert_eq!(
+        files[&PathBuf::from("/repo/legacy/.tool-versions")].versions("nodejs"),
+        None
+    );
+}
+
+#[test]
+fn it_leaves_files_untouched_on_invalid_versions() {
+    let mut files = files();
+    let before: Vec<String> = files.values().map(ToolVersions::write).collect();
+
+    assert_eq!(
+        consistency::align(&mut files, "nodejs", vec!["20.9", "1 # 2"], &[]),
+        Err(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Version",
+        })
+    );
+    assert_eq!(
+        files
+            .values()
+            .map(ToolVersions::write)
+            .collect::<Vec<String>>(),
+        before
+    );
+}
+
+#[test]
+fn it_aligns_scanned_files() {
+    let root = TempDir::new("_tool-versions-consistency");
+
+    fs::create_dir_all(root.join("api")).unwrap();
+    fs::write(root.join(".tool-versions"), "nodejs 20.9\n").unwrap();
+    fs::write(root.join("api/.tool-versions"), "nodejs   18 # api\n").unwrap();
+
+    let mut files = scanner::scan(&root, &Options::default()).files;
+    let changed = consistency::align(&mut files, "nodejs", vec!["20.9"], &[]).unwrap();
+
+    consistency::save(&files, &changed).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(root.join("api/.tool-versions")).unwrap(),
+        "nodejs   20.9 # api\n"
+    );
+    assert!(consistency::check(&scanner::scan(&root, &Options::default()).files, &[]).is_empty());
+}
@@ -0,0 +1,117 @@
+use tool_versions::{
+    ast::SyntaxError,
+    diff::{self, Change, Options},
+    ToolVersions,
+};
+
+const OLD: &str = "# pins\nnodejs 18.12 system # lts\nruby 3.1 3.2\nlua 5.4\n+broken\n";
+const NEW: &str = "# pins  \nnodejs  20.9 system 18.12 # current\nruby 3.2 3.1\ngolang 1.22\n# new\n+broken\n-also\n";
+
+#[test]
+fn it_diffs_semantic_changes() {
+    let changes = diff::diff(
+        &ToolVersions::from(OLD),
+        &ToolVersions::from(NEW),
+        &Options::default(),
+    );
+
+    assert_eq!(
+        changes,
+        vec![
+            Change::ToolRemoved {
+                tool_name: "lua".to_string(),
+                line: 4,
+                versions: vec!["5.4".to_string()],
+            },
+            Change::VersionsChanged {
+                tool_name: "nodejs".to_string(),
+                line: 2,
+                old: vec!["18.12".to_string(), "system".to_string()],
+                new: vec![
+                    "20.9".to_string(),
+                    "system".to_string(),
+                    "18.12".to_string()
+                ],
+                added: vec!["20.9".to_string()],
+                removed: vec![],
+                reordered: true,
+            },
+            Change::ToolCommentChanged {
+                tool_name: "nodejs".to_string(),
+                line: 2,
+                old: Some(" lts".to_string()),
+                new: Some(" current".to_string()),
+            },
+            Change::VersionsChanged {
+                tool_name: "ruby".to_string(),
+                line: 3,
+                old: vec!["3.1".to_string(), "3.2".to_string()],
+                new: vec!["3.2".to_string(), "3.1".to_string()],
+                added: vec![],
+                removed: vec![],
+                reordered: true,
+            },
+            Change::ToolAdded {
+                tool_name: "golang".to_string(),
+                line: 4,
+                versions: vec!["1.22".to_string()],
+            },
+            Change::CommentAdded {
+                line: 5,
+                comment: "new".to_string(),
+            },
+            Change::SyntaxErrorAdded {
+                line: 7,
+                error: SyntaxError::UnexpectedEOL {
+                    expected: "Whitespace",
+                },
+                unparsed: "-also".to_string(),
+            },
+        ]
+    );
+
+    assert_eq!(
+        diff::render(&changes),
+        "- lua 5.4 (line 4)\n~ nodejs 18.12 system -> 20.9 system 18.12 (line 2; added 20.9; reordered)\n~ nodejs comment \" lts\" -> \" current\" (line 2)\n~ ruby 3.1 3.2 -> 3.2 3.1 (line 3; reordered)\n+ golang 1.22 (line 4)\n+ #new (line 5)\n! unexpected end of line, expected Whitespace (line 7: \"-also\")\n"
+    );
+}
+
+#[test]
+fn it_diffs_whitespace_when_requested() {
+    let old = ToolVersions::from("# pins\nnodejs 18.12\n");
+    let new = ToolVersions::from("# pins \nnodejs  18.12\n");
+
+    assert_eq!(diff::diff(&old, &new, &Options::default()), vec![]);
+
+    assert_eq!(
+        diff::diff(&old, &new, &Options { whitespace: true }),
+        vec![
+            Change::WhitespaceChanged {
+                tool_name: "nodejs".to_string(),
+                line: 2,
+            },
+            Change::CommentRemoved {
+                line: 1,
+                comment: " pins".to_string(),
+            },
+            Change::CommentAdded {
+                line: 1,
+                comment: " pins ".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_renders_json() {
+    let changes = diff::diff(
+        &ToolVersions::from("nodejs 18 # old\n"),
+        &ToolVersions::from("nodejs 20\nruby \"3\"\n"),
+        &Options::default(),
+    );
+
+    assert_eq!(
+        diff::to_json(&changes),
+        "[{\"type\":\"versions_changed\",\"tool\":\"nodejs\",\"line\":1,\"old\":[\"18\"],\"new\":[\"20\"],\"added\":[\"20\"],\"removed\":[\"18\"],\"reordered\":false},{\"type\":\"tool_comment_changed\",\"tool\":\"nodejs\",\"line\":1,\"old\":\" old\",\"new\":null},{\"type\":\"tool_added\",\"tool\":\"ruby\",\"line\":2,\"versions\":[\"\\\"3\\\"\"]}]"
+    );
+}
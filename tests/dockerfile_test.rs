@@ -0,0 +1,169 @@
+use tool_versions::{
+    dockerfile::{self, Arg, ArgMapping, Drift},
+    ToolVersions,
+};
+
+const DOCKERFILE: &str = "FROM debian\nARG NODE_VERSION=18.12.0\narg RUBY_VERSION=\"3.1\"\nARG TERRAFORM_VERSION\nARG GO_VERSION=1.21 OTHER=1\nRUN echo $NODE_VERSION\n";
+
+#[test]
+fn it_parses_args() {
+    assert_eq!(
+        dockerfile::parse_args(DOCKERFILE),
+        vec![
+            Arg {
+                line: 2,
+                name: "NODE_VERSION".to_string(),
+                value: Some("18.12.0".to_string()),
+            },
+            Arg {
+                line: 3,
+                name: "RUBY_VERSION".to_string(),
+                value: Some("3.1".to_string()),
+            },
+            Arg {
+                line: 4,
+                name: "TERRAFORM_VERSION".to_string(),
+                value: None,
+            },
+            Arg {
+                line: 5,
+                name: "GO_VERSION".to_string(),
+                value: Some("1.21".to_string()),
+            },
+            Arg {
+                line: 5,
+                name: "OTHER".to_string(),
+                value: Some("1".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_reports_drift() {
+    let tools =
+        ToolVersions::from("nodejs 18.12.0\nruby 3.2 3.1\nterraform 1.5.0\ngolang system\n");
+
+    assert_eq!(
+        dockerfile::drift(DOCKERFILE, &tools, &ArgMapping::new()),
+        vec![
+            Drift {
+                tool_name: "ruby".to_string(),
+                arg: Arg {
+                    line: 3,
+                    name: "RUBY_VERSION".to_string(),
+                    value: Some("3.1".to_string()),
+                },
+                expected: "3.2".to_string(),
+            },
+            Drift {
+                tool_name: "terraform".to_string(),
+                arg: Arg {
+                    line: 4,
+                    name: "TERRAFORM_VERSION".to_string(),
+                    value: None,
+                },
+                expected: "1.5.0".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_updates_args() {
+    let tools = ToolVersions::from("nodejs 20.9.0\nruby 3.2\nterraform 1.5.0\ngolang 1.22\n");
+
+    assert_eq!(
+        dockerfile::update(DOCKERFILE, &tools, &ArgMapping::new()),
+        "FROM debian\nARG NODE_VERSION=20.9.0\narg RUBY_VERSION=\"3.2\"\nARG TERRAFORM_VERSION=1.5.0\nARG GO_VERSION=1.22 OTHER=1\nRUN echo $NODE_VERSION\n"
+    );
+}
+
+#[test]
+fn it_uses_custom_mapping() {
+    let tools = ToolVersions::from("nodejs 20.9.0\nfoo-bar 1\n");
+    let mapping = ArgMapping::new().with("nodejs", "NODEJS_TAG");
+
+    assert_eq!(
+        dockerfile::arg_block(&tools, &mapping),
+        "ARG NODEJS_TAG=20.9.0\nARG FOO_BAR_VERSION=1\n"
+    );
+
+    assert_eq!(
+        dockerfile::update("ARG NODEJS_TAG=18\nARG NODE_VERSION=18\n", &tools, &mapping),
+        "ARG NODEJS_TAG=20.9.0\nARG NODE_VERSION=18\n"
+    );
+}
+
+#[test]
+fn it_keeps_quoted_values_together() {
+    let dockerfile = "ARG LABEL=\"a b\" NODE_VERSION=18 RUBY_VERSION='3.1 p1'\n";
+    let tools = ToolVersions::from("nodejs 20.9.0\nruby 3.2\n");
+
+    assert_eq!(
+        dockerfile::parse_args(dockerfile),
+        vec![
+            Arg {
+                line: 1,
+                name: "LABEL".to_string(),
+                value: Some("a b".to_string()),
+            },
+            Arg {
+                line: 1,
+                name: "NODE_VERSION".to_string(),
+                value: Some("18".to_string()),
+            },
+            Arg {
+                line: 1,
+                name: "RUBY_VERSION".to_string(),
+                value: Some("3.1 p1".to_string()),
+            },
+        ]
+    );
+
+    assert_eq!(
+        dockerfile::update(dockerfile, &tools, &ArgMapping::new()),
+        "ARG LABEL=\"a b\" NODE_VERSION=20.9.0 RUBY_VERSION='3.2'\n"
+    );
+}
+
+#[test]
+fn it_follows_continuation_lines() {
+    let dockerfile =
+        "FROM debian\nARG NODE_VERSION=18.12.0 \\\n    RUBY_VERSION=3.1 \\\n    TERRAFORM_VERSION\nRUN echo $NODE_VERSION \\\n    ARG=1\n";
+    let tools = ToolVersions::from("nodejs 18.12.0\nruby 3.2\nterraform 1.5.0\n");
+
+    assert_eq!(
+        dockerfile::parse_args(dockerfile),
+        vec![
+            Arg {
+                line: 2,
+                name: "NODE_VERSION".to_string(),
+                value: Some("18.12.0".to_string()),
+            },
+            Arg {
+                line: 3,
+                name: "RUBY_VERSION".to_string(),
+                value: Some("3.1".to_string()),
+            },
+            Arg {
+                line: 4,
+                name: "TERRAFORM_VERSION".to_string(),
+                value: None,
+            },
+        ]
+    );
+
+    assert_eq!(
+        dockerfile::drift(dockerfile, &tools, &ArgMapping::new())
+            .iter()
+            .map(|drift| drift.tool_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ruby", "terraform"]
+    );
+
+    assert_eq!(
+        dockerfile::update(dockerfile, &tools, &ArgMapping::new()),
+        "FROM debian\nARG NODE_VERSION=18.12.0 \\\n    RUBY_VERSION=3.2 \\\n    TERRAFORM_VERSION=1.5.0\nRUN echo $NODE_VERSION \\\n    ARG=1\n"
+    );
+}
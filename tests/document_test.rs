@@ -0,0 +1,173 @@
+use tool_versions::{
+    ast::{Identifier, Node, SyntaxError, Unparsed, Version, AST},
+    document::Document,
+    parser, writer,
+};
+
+fn identifier(name: &str) -> Identifier {
+    Identifier::new(name.to_string())
+}
+
+fn versions(versions: &[&str]) -> Vec<Version> {
+    versions
+        .iter()
+        .map(|version| Version::new(version.to_string()))
+        .collect()
+}
+
+#[test]
+fn it_indexes_tool_definitions() {
+    let document = Document::new(parser::parse(
+        "# tools\nnodejs 18.12 system\nruby 3.2\nnodejs 20.9\n",
+    ));
+
+    assert_eq!(document.line_index(&identifier("nodejs")), Some(1));
+    assert_eq!(document.line_index(&identifier("ruby")), Some(2));
+    assert_eq!(document.line_index(&identifier("lua")), None);
+    assert_eq!(
+        document
+            .versions(&identifier("ruby"))
+            .map(|versions| versions.value().len()),
+        Some(1)
+    );
+}
+
+#[test]
+fn it_edits_in_place() {
+    let mut document = Document::new(parser::parse(
+        "nodejs  18.12    system  # lts\nruby 3.2\n# end\nlua 5.4\n",
+    ));
+
+    document
+        .set_versions(identifier("nodejs"), versions(&["20.9", "18.12", "system"]))
+        .unwrap();
+    document.set_versions(identifier("ruby"), vec![]).unwrap();
+    document
+        .append_version(identifier("golang"), Version::new("1.22".to_string()))
+        .unwrap();
+    document
+        .promote_version(identifier("lua"), Version::new("5.1".to_string()))
+        .unwrap();
+    document
+        .promote_version(identifier("deno"), Version::new("1.40".to_string()))
+        .unwrap();
+
+    assert_eq!(document.line_index(&identifier("ruby")), None);
+    assert_eq!(document.line_index(&identifier("lua")), Some(2));
+    assert_eq!(document.line_index(&identifier("golang")), Some(3));
+    assert_eq!(
+        writer::write(document.ast()),
+        "nodejs 20.9  18.12    system  # lts\n# end\nlua 5.4\ngolang 1.22\n"
+    );
+
+    let ast = document.into_ast();
+    assert_eq!(
+        Document::new(parser::parse(&writer::write(&ast))).into_ast(),
+        ast
+    );
+}
+
+#[test]
+fn it_edits_every_definition_of_a_tool() {
+    let mut lines = parser::parse("nodejs 18\nruby 3\n").lines;
+    lines.extend(parser::parse("nodejs  20 # second\n").lines);
+
+    let mut document = Document::new(AST { lines });
+
+    assert_eq!(document.line_indices(&identifier("nodejs")), &[0, 2]);
+
+    document
+        .set_versions(identifier("nodejs"), versions(&["21"]))
+        .unwrap();
+
+    assert_eq!(
+        writer::write(document.ast()),
+        "nodejs 21\nruby 3\nnodejs  21 # second\n"
+    );
+
+    assert!(document
+        .rename(&identifier("nodejs"), identifier("node"))
+        .unwrap());
+    assert_eq!(document.line_indices(&identifier("node")), &[0, 2]);
+
+    document.set_versions(identifier("node"), vec![]).unwrap();
+
+    assert_eq!(document.line_index(&identifier("node")), None);
+    assert_eq!(document.line_index(&identifier("ruby")), Some(0));
+    assert_eq!(writer::write(document.ast()), "ruby 3\n");
+}
+
+#[test]
+fn it_rejects_invalid_nodes_and_conflicted_tools() {
+    let mut document = Document::new(parser::parse(
+        "<<<<<<< ours\nnodejs 20.9\n=======\nnodejs 21.1\n>>>>>>> theirs\nruby 3.2\n",
+    ));
+
+    assert_eq!(
+        document.set_versions(identifier("my tool"), versions(&["1"])),
+        Err(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Identifier",
+        })
+    );
+    assert_eq!(
+        document.append_version(identifier("ruby"), Version::new("1 # 2".to_string())),
+        Err(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Version",
+        })
+    );
+    assert_eq!(
+        document.set_versions(identifier("nodejs"), versions(&["22"])),
+        Err(SyntaxError::ConflictMarker)
+    );
+    assert_eq!(
+        document.rename(&identifier("ruby"), identifier("nodejs")),
+        Err(SyntaxError::ConflictMarker)
+    );
+    assert_eq!(
+        document.set_comment(
+            &identifier("ruby"),
+            Some(Unparsed::new(" a\nb".to_string()))
+        ),
+        Err(SyntaxError::UnexpectedToken {
+            token: '\n',
+            expected: "Comment",
+        })
+    );
+
+    let ast = document.into_ast();
+    assert_eq!(parser::parse(&writer::write(&ast)), ast);
+}
+
+#[test]
+fn it_keeps_padding_with_its_version() {
+    let mut document = Document::new(parser::parse("nodejs  18.12    system  # lts\n"));
+
+    document
+        .set_versions(identifier("nodejs"), versions(&["20.9", "system"]))
+        .unwrap();
+
+    assert_eq!(
+        writer::write(document.ast()),
+        "nodejs  20.9    system  # lts\n"
+    );
+
+    document
+        .promote_version(identifier("nodejs"), Version::new("system".to_string()))
+        .unwrap();
+
+    assert_eq!(
+        writer::write(document.ast()),
+        "nodejs    system  20.9  # lts\n"
+    );
+
+    document
+        .prepend_version(identifier("nodejs"), Version::new("21.6".to_string()))
+        .unwrap();
+
+    assert_eq!(
+        writer::write(document.ast()),
+        "nodejs 21.6    system  20.9  # lts\n"
+    );
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::TempDir;
+use std::{env, fs, path::PathBuf};
+use tool_versions::{exec, inventory::Inventory, resolver, ToolVersions};
+
+fn data_dir(name: &str) -> TempDir {
+    let dir = TempDir::new(name);
+
+    for path in [
+        "plugins/nodejs",
+        "plugins/ruby",
+        "installs/nodejs/20.9/bin",
+        "installs/ruby/3.2",
+        "local/lua/bin",
+    ] {
+        fs::create_dir_all(dir.join(path)).unwrap();
+    }
+
+    dir
+}
+
+#[test]
+fn it_computes_environment() {
+    let dir = data_dir("_asdf-exec-environment");
+    let inventory = Inventory::new(&dir);
+    let tools = ToolVersions::from(&format!(
+        "nodejs 18 20.9\nruby 3.2\nlua path:{}\npython system\n",
+        dir.join("local/lua").display()
+    ));
+
+    let environment = exec::environment(&resolver::resolve(&tools, &inventory), &inventory);
+
+    assert_eq!(
+        environment.path,
+        vec![
+            dir.join("installs/nodejs/20.9/bin"),
+            dir.join("installs/ruby/3.2"),
+            dir.join("local/lua/bin"),
+        ]
+    );
+
+    assert_eq!(
+        environment.vars,
+        vec![
+            ("ASDF_NODEJS_VERSION".to_string(), "20.9".to_string()),
+            ("ASDF_RUBY_VERSION".to_string(), "3.2".to_string()),
+            (
+                "ASDF_LUA_VERSION".to_string(),
+                format!("path:{}", dir.join("local/lua").display())
+            ),
+            ("ASDF_PYTHON_VERSION".to_string(), "system".to_string()),
+        ]
+    );
+
+    assert_eq!(
+        environment.path_value(Some("/usr/bin".as_ref())).unwrap(),
+        env::join_paths([
+            dir.join("installs/nodejs/20.9/bin"),
+            dir.join("installs/ruby/3.2"),
+            dir.join("local/lua/bin"),
+            PathBuf::from("/usr/bin"),
+        ])
+        .unwrap()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn it_fails_on_paths_containing_the_separator() {
+    let environment = exec::Environment {
+        path: vec![PathBuf::from("/opt/a:b")],
+        vars: vec![],
+    };
+
+    assert!(environment.path_value(None).is_err());
+    assert!(exec::command("node", &environment).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn it_spawns_command_with_environment() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = data_dir("_asdf-exec-command");
+    let inventory = Inventory::new(&dir);
+    let tools = ToolVersions::from("nodejs 20.9\n");
+
+    let node = dir.join("installs/nodejs/20.9/bin/node");
+    fs::write(
+        &node,
+        "#!/bin/sh\necho \"fake node $ASDF_NODEJS_VERSION\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&node, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let environment = exec::environment(&resolver::resolve(&tools, &inventory), &inventory);
+    let output = exec::command("node", &environment)
+        .unwrap()
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "fake node 20.9\n"
+    );
+}
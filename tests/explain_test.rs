@@ -0,0 +1,141 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tool_versions::{
+    asdfrc::Config,
+    explain::{self, Context, Source, Step},
+    files::{self, Locations},
+};
+
+fn temp_tree(name: &str) -> TempDir {
+    let root = TempDir::new(name);
+
+    fs::create_dir_all(root.join("home/project/service")).unwrap();
+
+    fs::write(root.join("home/.tool-versions"), "ruby 3.1\nnodejs 16\n").unwrap();
+    fs::write(
+        root.join("home/project/.tool-versions"),
+        "# pins\nruby 3.2\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("home/project/service/.tool-versions"),
+        "lua 5.4\n",
+    )
+    .unwrap();
+    fs::write(root.join("home/project/service/.nvmrc"), "\n20.9\n").unwrap();
+
+    root
+}
+
+#[test]
+fn it_explains_file_resolution() {
+    let root = temp_tree("_tool-versions-explain-file");
+    let home = root.join("home");
+    let context = Context::new(
+        home.join("project/service"),
+        Locations::new(&home, files::DEFAULT_FILENAME),
+        Config::default(),
+    );
+
+    let explanation = explain::explain("ruby", &context).unwrap();
+
+    assert_eq!(explanation.versions, Some(vec!["3.2".to_string()]));
+    assert_eq!(
+        explanation.source,
+        Some(Source::File {
+            path: home.join("project/.tool-versions"),
+            line: 2,
+        })
+    );
+    assert_eq!(
+        explanation.steps,
+        vec![
+            Step::EnvVar {
+                name: "ASDF_RUBY_VERSION".to_string(),
+                value: None,
+            },
+            Step::File {
+                path: home.join("project/service/.tool-versions"),
+                global: false,
+                found: None,
+            },
+            Step::File {
+                path: home.join("project/.tool-versions"),
+                global: false,
+                found: Some((2, vec!["3.2".to_string()])),
+            },
+        ]
+    );
+
+    assert_eq!(
+        explain::render(&explanation),
+        format!(
+            "ruby 3.2\n  ASDF_RUBY_VERSION is not set\n  {}: no entry\n  {}:2: ruby 3.2 (selected)\n",
+            home.join("project/service/.tool-versions").display(),
+            home.join("project/.tool-versions").display()
+        )
+    );
+}
+
+#[test]
+fn it_explains_env_var_and_legacy_files() {
+    let root = temp_tree("_tool-versions-explain-legacy");
+    let home = root.join("home");
+    let mut context = Context::new(
+        home.join("project/service"),
+        Locations::new(&home, files::DEFAULT_FILENAME),
+        Config::parse("legacy_version_file = yes\n"),
+    );
+
+    let explanation = explain::explain("nodejs", &context).unwrap();
+
+    assert_eq!(explanation.versions, Some(vec!["20.9".to_string()]));
+    assert_eq!(
+        explanation.source,
+        Some(Source::LegacyFile {
+            path: home.join("project/service/.nvmrc"),
+        })
+    );
+
+    context
+        .env_vars
+        .insert("ASDF_NODEJS_VERSION".to_string(), "18 system".to_string());
+
+    let explanation = explain::explain("nodejs", &context).unwrap();
+
+    assert_eq!(
+        explanation.versions,
+        Some(vec!["18".to_string(), "system".to_string()])
+    );
+    assert_eq!(explanation.steps.len(), 1);
+}
+
+#[test]
+fn it_falls_back_to_global_file() {
+    let root = temp_tree("_tool-versions-explain-global");
+    let home = root.join("home");
+    let context = Context::new(
+        root.join("home/project/service"),
+        Locations::new(&home, files::DEFAULT_FILENAME),
+        Config::default(),
+    );
+
+    let explanation = explain::explain("nodejs", &context).unwrap();
+
+    assert_eq!(explanation.versions, Some(vec!["16".to_string()]));
+    assert_eq!(
+        explanation.steps.last(),
+        Some(&Step::File {
+            path: home.join(".tool-versions"),
+            global: true,
+            found: Some((2, vec!["16".to_string()])),
+        })
+    );
+
+    let explanation = explain::explain("python", &context).unwrap();
+
+    assert_eq!(explanation.source, None);
+    assert!(explain::render(&explanation).starts_with("python: no version set\n"));
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::TempDir;
+use std::{fs, path::PathBuf};
+use tool_versions::files::{self, Locations};
+
+#[test]
+fn it_locates_files() {
+    let locations = Locations::new("/home/user", ".custom-versions");
+
+    assert_eq!(
+        locations.global().unwrap(),
+        PathBuf::from("/home/user/.custom-versions")
+    );
+
+    assert_eq!(
+        locations.local("/project"),
+        PathBuf::from("/project/.custom-versions")
+    );
+
+    let locations = Locations {
+        home: None,
+        filename: files::DEFAULT_FILENAME.to_string(),
+    };
+
+    assert!(locations.global().is_err());
+}
+
+#[test]
+fn it_sets_and_unsets_global() {
+    let home = TempDir::new("_tool-versions-files-global");
+    let locations = Locations::new(&home, files::DEFAULT_FILENAME);
+
+    locations.unset_global("nodejs").unwrap();
+
+    assert!(!home.join(".tool-versions").exists());
+
+    locations.set_global("nodejs", vec!["18.12"]).unwrap();
+    locations.set_global("ruby", vec!["3.2", "system"]).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(home.join(".tool-versions")).unwrap(),
+        "nodejs 18.12\nruby 3.2 system\n"
+    );
+
+    locations.unset_global("nodejs").unwrap();
+
+    assert_eq!(
+        fs::read_to_string(home.join(".tool-versions")).unwrap(),
+        "ruby 3.2 system\n"
+    );
+}
+
+#[test]
+fn it_preserves_local_content() {
+    let dir = TempDir::new("_tool-versions-files-local");
+    let locations = Locations::new("/unused", ".custom-versions");
+
+    fs::write(
+        dir.join(".custom-versions"),
+        "# pinned\nnodejs  18.12   system # lts\nlua 5.4\n",
+    )
+    .unwrap();
+
+    locations
+        .set_local(&dir, "nodejs", vec!["20.9", "system"])
+        .unwrap();
+    locations.unset_local(&dir, "lua").unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.join(".custom-versions")).unwrap(),
+        "# pinned\nnodejs  20.9   system # lts\n"
+    );
+}
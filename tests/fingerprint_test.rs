@@ -0,0 +1,65 @@
+use tool_versions::{fingerprint, ToolVersions};
+
+#[test]
+fn it_builds_canonical_form() {
+    let tools = ToolVersions::from("# tools\npython   3.12.1 # latest\n\nnodejs 18.19.0 system\n");
+
+    assert_eq!(
+        fingerprint::canonical(&tools, None),
+        "nodejs 18.19.0 system\npython 3.12.1\n"
+    );
+    assert_eq!(
+        fingerprint::canonical(&tools, Some(&["python", "ruby"])),
+        "python 3.12.1\n"
+    );
+}
+
+#[test]
+fn it_produces_stable_fingerprints() {
+    assert_eq!(
+        fingerprint::fingerprint(&ToolVersions::new(), None),
+        "v1:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        fingerprint::fingerprint(
+            &ToolVersions::from("nodejs 18.19.0 system\npython 3.12.1\n"),
+            None
+        ),
+        "v1:6dc80d9e39341fa1a3a30fd69d6686b4a34ab9209c4a4642ca334b0776f6e746"
+    );
+    assert_eq!(
+        fingerprint::fingerprint(
+            &ToolVersions::from(
+                "ruby 3.3.0\npython 3.12.1 3.11.7\nnodejs 18.19.0 20.11.1 system\ngolang 1.22.1\n"
+            ),
+            None
+        ),
+        "v1:eb6ed14e599b9edf3d8ee7eac79bf061dbf08964a630ce4e50c65a49709668c1"
+    );
+}
+
+#[test]
+fn it_ignores_layout_and_comments() {
+    let tools = ToolVersions::from("nodejs 18.19.0 system\npython 3.12.1\n");
+    let edited =
+        ToolVersions::from("# pinned\npython\t3.12.1   # keep\nnodejs 18.19.0 system\n\nruby\n");
+
+    assert_eq!(
+        fingerprint::fingerprint(&tools, None),
+        fingerprint::fingerprint(&edited, None)
+    );
+    assert_ne!(
+        fingerprint::fingerprint(&tools, None),
+        fingerprint::fingerprint(
+            &ToolVersions::from("nodejs system 18.19.0\npython 3.12.1\n"),
+            None
+        )
+    );
+    assert_eq!(
+        fingerprint::fingerprint(&tools, Some(&["python"])),
+        fingerprint::fingerprint(
+            &ToolVersions::from("python 3.12.1\nruby 3.3.0\n"),
+            Some(&["python"])
+        )
+    );
+}
@@ -0,0 +1,55 @@
+use tool_versions::{github_actions, ToolVersions};
+
+#[test]
+fn it_generates_setup_steps() {
+    let tools = ToolVersions::from(
+        "nodejs 18.12 20.9\npython system 3.11\nlua 5.4\nruby 3.2.2 # comment\njava temurin-17.0.2+8\ngolang system\n",
+    );
+
+    assert_eq!(
+        github_actions::setup_steps(&tools),
+        "- uses: actions/setup-node@v4\n  with:\n    node-version: '18.12'\n- uses: actions/setup-python@v5\n  with:\n    python-version: '3.11'\n- uses: ruby/setup-ruby@v1\n  with:\n    ruby-version: '3.2.2'\n- uses: actions/setup-java@v4\n  with:\n    distribution: 'temurin'\n    java-version: '17.0.2+8'\n"
+    );
+}
+
+#[test]
+fn it_generates_matrix() {
+    let tools = ToolVersions::from("nodejs 18.12 20.9\nlua 5.4\ngolang system\nfoo.bar 1'2\n");
+
+    assert_eq!(
+        github_actions::matrix(&tools),
+        "strategy:\n  matrix:\n    nodejs: ['18.12', '20.9']\n    lua: ['5.4']\n    'foo.bar': ['1''2']\n"
+    );
+}
+
+#[test]
+fn it_generates_matrix_setup_steps() {
+    let tools = ToolVersions::from("nodejs 18.12 20.9\nlua 5.4\njava 17\ngolang system\n");
+
+    assert_eq!(
+        github_actions::matrix_setup_steps(&tools),
+        "- uses: actions/setup-node@v4\n  with:\n    node-version: ${{ matrix.nodejs }}\n- uses: actions/setup-java@v4\n  with:\n    distribution: ${{ matrix.java.distribution }}\n    java-version: ${{ matrix.java.version }}\n"
+    );
+}
+
+#[test]
+fn it_generates_nothing_for_empty_file() {
+    let tools = ToolVersions::new();
+
+    assert_eq!(github_actions::setup_steps(&tools), "");
+    assert_eq!(github_actions::matrix(&tools), "");
+    assert_eq!(
+        github_actions::matrix(&ToolVersions::from("nodejs system\nlua path:/opt/lua\n")),
+        ""
+    );
+}
+
+#[test]
+fn it_splits_java_distribution_in_matrix() {
+    let tools = ToolVersions::from("java temurin-17.0.2+8 corretto-21.0.1 11\n");
+
+    assert_eq!(
+        github_actions::matrix(&tools),
+        "strategy:\n  matrix:\n    java: [{ distribution: 'temurin', version: '17.0.2+8' }, { distribution: 'corretto', version: '21.0.1' }, { distribution: 'temurin', version: '11' }]\n"
+    );
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tool_versions::{
+    inventory::{Inventory, Status, ToolStatus},
+    ToolVersions,
+};
+
+fn data_dir(name: &str) -> TempDir {
+    let dir = TempDir::new(name);
+
+    for path in [
+        "plugins/nodejs",
+        "plugins/ruby",
+        "plugins/golang",
+        "installs/nodejs/18.12",
+        "installs/nodejs/20.9",
+        "installs/golang/ref-abc123",
+    ] {
+        fs::create_dir_all(dir.join(path)).unwrap();
+    }
+
+    dir
+}
+
+#[test]
+fn it_lists_plugins_and_installs() {
+    let dir = data_dir("_asdf-inventory-list");
+    let inventory = Inventory::new(&dir);
+
+    assert_eq!(
+        inventory.plugins().unwrap(),
+        vec![
+            "golang".to_string(),
+            "nodejs".to_string(),
+            "ruby".to_string()
+        ]
+    );
+
+    assert_eq!(
+        inventory.installed_versions("nodejs").unwrap(),
+        vec!["18.12".to_string(), "20.9".to_string()]
+    );
+
+    assert_eq!(
+        inventory.installed_versions("ruby").unwrap(),
+        Vec::<String>::new()
+    );
+
+    assert!(inventory.is_installed("golang", "ref:abc123"));
+}
+
+#[test]
+fn it_reports_status() {
+    let dir = data_dir("_asdf-inventory-report");
+    let inventory = Inventory::new(&dir);
+    let tools = ToolVersions::from(&format!(
+        "nodejs 20.9 16 system\nruby 3.2\nlua 5.4\ngolang path:{} path:/nonexistent\n",
+        dir.display()
+    ));
+
+    assert_eq!(
+        inventory.report(&tools),
+        vec![
+            ToolStatus {
+                tool_name: "nodejs".to_string(),
+                versions: vec![
+                    ("20.9".to_string(), Status::Installed),
+                    ("16".to_string(), Status::NotInstalled),
+                    ("system".to_string(), Status::System),
+                ],
+            },
+            ToolStatus {
+                tool_name: "ruby".to_string(),
+                versions: vec![("3.2".to_string(), Status::NotInstalled)],
+            },
+            ToolStatus {
+                tool_name: "lua".to_string(),
+                versions: vec![("5.4".to_string(), Status::PluginMissing)],
+            },
+            ToolStatus {
+                tool_name: "golang".to_string(),
+                versions: vec![
+                    (
+                        format!("path:{}", dir.display()),
+                        Status::Path { exists: true }
+                    ),
+                    (
+                        "path:/nonexistent".to_string(),
+                        Status::Path { exists: false }
+                    ),
+                ],
+            },
+        ]
+    );
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tool_versions::{
+    asdfrc::Config,
+    inventory::Inventory,
+    latest::{self, Options, Resolved},
+    upgrade::FileIndex,
+    ToolVersions,
+};
+
+fn versions(versions: &[&str]) -> Vec<String> {
+    versions.iter().map(|version| version.to_string()).collect()
+}
+
+#[test]
+fn it_resolves_specs_against_version_list() {
+    let available = versions(&["18.9.0", "18.19.0", "18.20.0-rc.1", "20.11.1", "21.0.0-dev"]);
+    let options = Options::default();
+
+    assert_eq!(
+        latest::resolve_spec("latest", &available, &options),
+        Some("20.11.1".to_string())
+    );
+    assert_eq!(
+        latest::resolve_spec("latest:18", &available, &options),
+        Some("18.19.0".to_string())
+    );
+    assert_eq!(
+        latest::resolve_spec("latest:16", &available, &options),
+        None
+    );
+    assert_eq!(latest::resolve_spec("18.19.0", &available, &options), None);
+    assert_eq!(
+        latest::resolve_spec(
+            "latest:18",
+            &available,
+            &Options {
+                include_unstable: true
+            }
+        ),
+        Some("18.20.0-rc.1".to_string())
+    );
+}
+
+#[test]
+fn it_filters_unstable_versions() {
+    for version in [
+        "1.0.0-dev",
+        "2.0.0-RC1",
+        "3.13.0a1",
+        "1.22rc2",
+        "5.0.0-beta.2",
+        "nightly-snapshot",
+    ] {
+        assert!(latest::is_unstable(version), "{}", version);
+    }
+
+    for version in ["1.0.0", "3.12.1", "temurin-17.0.9+9"] {
+        assert!(!latest::is_unstable(version), "{}", version);
+    }
+}
+
+#[test]
+fn it_reads_options_from_config() {
+    assert!(!Options::from_config(&Config::parse("")).include_unstable);
+    assert!(
+        Options::from_config(&Config::parse("use_release_candidates = yes\n")).include_unstable
+    );
+}
+
+#[test]
+fn it_resolves_and_pins_from_index() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let mut tools = ToolVersions::from("nodejs latest:18 # lts\npython  latest\nruby latest\n");
+
+    let resolved = latest::resolve(&tools, &index, &Options::default()).unwrap();
+
+    assert_eq!(
+        resolved,
+        vec![
+            Resolved {
+                tool_name: "nodejs".to_string(),
+                line: Some(1),
+                spec: "latest:18".to_string(),
+                version: Some("18.19.0".to_string()),
+            },
+            Resolved {
+                tool_name: "python".to_string(),
+                line: Some(2),
+                spec: "latest".to_string(),
+                version: Some("3.12.1".to_string()),
+            },
+            Resolved {
+                tool_name: "ruby".to_string(),
+                line: Some(3),
+                spec: "latest".to_string(),
+                version: None,
+            },
+        ]
+    );
+
+    latest::pin(&mut tools, &resolved).unwrap();
+
+    assert_eq!(
+        tools.write(),
+        "nodejs 18.19.0 # lts\npython  3.12.1\nruby latest\n"
+    );
+}
+
+#[test]
+fn it_resolves_from_installed_versions() {
+    let dir = TempDir::new("_asdf-latest-installed");
+
+    for path in ["installs/nodejs/18.12.0", "installs/nodejs/20.9.0"] {
+        fs::create_dir_all(dir.join(path)).unwrap();
+    }
+
+    let tools = ToolVersions::from("nodejs latest\n");
+    let resolved = latest::resolve(&tools, &Inventory::new(&dir), &Options::default()).unwrap();
+
+    assert_eq!(resolved[0].version, Some("20.9.0".to_string()));
+}
@@ -0,0 +1,84 @@
+use tool_versions::{
+    lifecycle::{self, Dataset, Date, Options, Status, Warning},
+    ToolVersions,
+};
+
+#[test]
+fn it_parses_dates() {
+    let date = Date::parse("2024-02-29").unwrap();
+
+    assert_eq!(date, Date::new(2024, 2, 29));
+    assert_eq!(date.to_string(), "2024-02-29");
+    assert_eq!(Date::new(1970, 1, 1).days_since_epoch(), 0);
+    assert_eq!(date.days_until(&Date::new(2024, 3, 1)), 1);
+    assert_eq!(
+        Date::new(2023, 12, 31).days_until(&Date::new(2025, 1, 1)),
+        367
+    );
+    assert_eq!(Date::parse("2024-13-01"), None);
+}
+
+#[test]
+fn it_loads_dataset() {
+    let dataset = Dataset::load_dir("tests/__fixtures__/eol").unwrap();
+
+    assert_eq!(dataset.cycle("nodejs", "18.12.0").unwrap().cycle, "18");
+    assert_eq!(dataset.cycle("python", "3.7.17").unwrap().cycle, "3.7");
+    assert_eq!(dataset.cycle("python", "3.10.13").unwrap().cycle, "3.10");
+    assert_eq!(dataset.cycle("python", "3.11.4").unwrap().cycle, "3");
+    assert_eq!(dataset.cycle("golang", "1.22.1").unwrap().cycle, "1.22");
+    assert_eq!(dataset.cycle("golang", "1.21.0"), None);
+    assert!(dataset.cycle("nodejs", "18.12.0").unwrap().lts);
+}
+
+#[test]
+fn it_warns_about_eol_versions() {
+    let dataset = Dataset::load_dir("tests/__fixtures__/eol").unwrap();
+    let tools =
+        ToolVersions::from("nodejs 20.9.0 16 15.0.0 18.19\npython 3.7.17 3.12.1\nruby 3.2\n");
+
+    assert_eq!(
+        lifecycle::check(
+            &tools,
+            &dataset,
+            &Options {
+                today: Date::new(2025, 3, 1),
+                horizon_days: 90,
+            }
+        ),
+        vec![
+            Warning {
+                tool_name: "nodejs".to_string(),
+                version: "16".to_string(),
+                line: Some(1),
+                cycle: "16".to_string(),
+                lts: true,
+                status: Status::EndOfLife(Some(Date::new(2023, 9, 11))),
+            },
+            Warning {
+                tool_name: "nodejs".to_string(),
+                version: "15.0.0".to_string(),
+                line: Some(1),
+                cycle: "15".to_string(),
+                lts: false,
+                status: Status::EndOfLife(None),
+            },
+            Warning {
+                tool_name: "nodejs".to_string(),
+                version: "18.19".to_string(),
+                line: Some(1),
+                cycle: "18".to_string(),
+                lts: true,
+                status: Status::EndingSoon(Date::new(2025, 4, 30)),
+            },
+            Warning {
+                tool_name: "python".to_string(),
+                version: "3.7.17".to_string(),
+                line: Some(2),
+                cycle: "3.7".to_string(),
+                lts: false,
+                status: Status::EndOfLife(Some(Date::new(2023, 6, 27))),
+            },
+        ]
+    );
+}
@@ -0,0 +1,146 @@
+use tool_versions::{
+    latest::Options,
+    lock::{self, Entry, Lock, LockError, Stale},
+    upgrade::FileIndex,
+    ToolVersions,
+};
+
+fn entry(tool_name: &str, spec: &str, version: &str, checksum: Option<&str>) -> Entry {
+    Entry {
+        tool_name: tool_name.to_string(),
+        spec: spec.to_string(),
+        version: version.to_string(),
+        checksum: checksum.map(String::from),
+    }
+}
+
+#[test]
+fn it_locks_resolved_versions() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let tools = ToolVersions::from("nodejs 18 latest system\npython 3.11.4\n");
+
+    let lock = lock::lock(&tools, &index, None, &Options::default()).unwrap();
+
+    assert_eq!(
+        lock.entries,
+        vec![
+            entry("nodejs", "18", "18.19.0", None),
+            entry("nodejs", "latest", "21.6.1", None),
+            entry("nodejs", "system", "system", None),
+            entry("python", "3.11.4", "3.11.4", None),
+        ]
+    );
+}
+
+#[test]
+fn it_keeps_previous_entries_when_updating() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let previous = Lock {
+        entries: vec![
+            entry("nodejs", "18", "18.12.0", Some("sha256:abc")),
+            entry("ruby", "3.2", "3.2.2", None),
+        ],
+    };
+    let tools = ToolVersions::from("nodejs 18\npython 3.12\n");
+
+    let lock = lock::lock(&tools, &index, Some(&previous), &Options::default()).unwrap();
+
+    assert_eq!(
+        lock.entries,
+        vec![
+            entry("nodejs", "18", "18.12.0", Some("sha256:abc")),
+            entry("python", "3.12", "3.12.1", None),
+        ]
+    );
+}
+
+#[test]
+fn it_resolves_aliases() {
+    let index = FileIndex::new("tests/__fixtures__/aliases");
+    let tools = ToolVersions::from("nodejs lts\n");
+
+    let lock = lock::lock(&tools, &index, None, &Options::default()).unwrap();
+
+    assert_eq!(lock.entries, vec![entry("nodejs", "lts", "20.11.1", None)]);
+}
+
+#[test]
+fn it_fails_on_unresolvable_specs() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let tools = ToolVersions::from("nodejs lts\n");
+
+    match lock::lock(&tools, &index, None, &Options::default()) {
+        Err(LockError::Unresolved { tool_name, spec }) => {
+            assert_eq!((tool_name.as_str(), spec.as_str()), ("nodejs", "lts"));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    let tools = ToolVersions::from("nodejs stable\n");
+
+    match lock::lock(&tools, &index, None, &Options::default()) {
+        Err(LockError::Unresolved { tool_name, spec }) => {
+            assert_eq!((tool_name.as_str(), spec.as_str()), ("nodejs", "stable"));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn it_writes_and_parses_lock_files() {
+    let mut lock = Lock {
+        entries: vec![
+            entry("nodejs", "latest:18", "18.19.0", None),
+            entry("python", "3.12", "3.12.1", None),
+        ],
+    };
+    lock.set_checksum("nodejs", "18.19.0", "sha256:0123abcd");
+
+    let written = lock.write();
+
+    assert_eq!(
+        written,
+        "# generated from .tool-versions\nnodejs latest:18 18.19.0 sha256:0123abcd\npython 3.12 3.12.1\n"
+    );
+    assert_eq!(Lock::parse(&written).unwrap(), lock);
+
+    match Lock::parse("nodejs 18\n") {
+        Err(error @ LockError::Parse { .. }) => assert_eq!(
+            error.to_string(),
+            "line 1: expected tool name, spec, version and optional checksum"
+        ),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn it_verifies_lock_against_source() {
+    let lock = Lock {
+        entries: vec![
+            entry("nodejs", "18", "18.19.0", None),
+            entry("ruby", "3.2", "3.2.2", None),
+        ],
+    };
+
+    assert_eq!(
+        lock::verify(&ToolVersions::from("ruby 3.2\nnodejs 18\n"), &lock),
+        Ok(())
+    );
+    assert_eq!(
+        lock::verify(&ToolVersions::from("nodejs 20\n"), &lock),
+        Err(vec![
+            Stale::Missing {
+                tool_name: "nodejs".to_string(),
+                spec: "20".to_string(),
+            },
+            Stale::Extra {
+                tool_name: "nodejs".to_string(),
+                spec: "18".to_string(),
+            },
+            Stale::Extra {
+                tool_name: "ruby".to_string(),
+                spec: "3.2".to_string(),
+            },
+        ])
+    );
+}
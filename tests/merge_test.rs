@@ -0,0 +1,146 @@
+mod common;
+
+use common::TempDir;
+use std::{fs, process::Command};
+use tool_versions::{merge, parser, writer};
+
+const BASE: &str = "# toolchain\nnodejs 18.12 system\nruby  3.1 # main\nlua 5.3\n";
+
+#[test]
+fn it_merges_different_tools() {
+    let ours = "# toolchain\nnodejs 20.9 system\nruby  3.1 # main\nlua 5.3\n";
+    let theirs = "# toolchain\nnodejs 18.12 system\nruby  3.2 # main\ngolang 1.22\n";
+
+    let result = merge::merge(
+        &parser::parse(BASE),
+        &parser::parse(ours),
+        &parser::parse(theirs),
+    );
+
+    assert!(result.is_clean());
+    assert_eq!(
+        result.write(),
+        "# toolchain\nnodejs 20.9 system\nruby  3.2 # main\ngolang 1.22\n"
+    );
+    assert_eq!(
+        writer::write(&result.to_ast().unwrap()),
+        "# toolchain\nnodejs 20.9 system\nruby  3.2 # main\ngolang 1.22\n"
+    );
+}
+
+#[test]
+fn it_keeps_layout_changes_from_either_side() {
+    let ours = "# toolchain\nnodejs 20.9 system\nruby  3.1 # main\nlua 5.3\n";
+    let theirs = "# toolchain, see README\n\nnodejs 18.12 system\nruby  3.1 # main\nlua 5.3\n";
+
+    let result = merge::merge(
+        &parser::parse(BASE),
+        &parser::parse(ours),
+        &parser::parse(theirs),
+    );
+
+    assert_eq!(
+        result.write(),
+        "# toolchain, see README\n\nnodejs 20.9 system\nruby  3.1 # main\nlua 5.3\n"
+    );
+}
+
+#[test]
+fn it_merges_comments_added_on_both_sides() {
+    let base = "nodejs 18\nruby 3.1\n";
+    let ours = "# ours note\nnodejs 20\nruby 3.1\n";
+    let theirs = "nodejs 18\nruby 3.2\n# theirs note\n";
+
+    let result = merge::merge(
+        &parser::parse(base),
+        &parser::parse(ours),
+        &parser::parse(theirs),
+    );
+
+    assert!(result.is_clean());
+    assert_eq!(
+        result.write(),
+        "# ours note\nnodejs 20\nruby 3.2\n# theirs note\n"
+    );
+}
+
+#[test]
+fn it_conflicts_on_different_layout_changes() {
+    let base = "nodejs 18\nruby 3.1\n";
+    let ours = "# ours note\nnodejs 20\nruby 3.1\n";
+    let theirs = "# theirs note\nnodejs 18\nruby 3.2\n";
+
+    let result = merge::merge(
+        &parser::parse(base),
+        &parser::parse(ours),
+        &parser::parse(theirs),
+    );
+
+    assert!(!result.is_clean());
+    assert_eq!(result.to_ast(), None);
+    assert_eq!(
+        result.write(),
+        "<<<<<<< ours\n# ours note\n=======\n# theirs note\n>>>>>>> theirs\nnodejs 20\nruby 3.2\n"
+    );
+}
+
+#[test]
+fn it_conflicts_on_same_tool() {
+    let ours = "# toolchain\nnodejs 20.9 system\nruby  3.1 # main\n";
+    let theirs = "# toolchain\nnodejs 21.1 system\nruby  3.1 # main\nlua 5.4\n";
+
+    let result = merge::merge(
+        &parser::parse(BASE),
+        &parser::parse(ours),
+        &parser::parse(theirs),
+    );
+
+    assert!(!result.is_clean());
+    assert_eq!(result.conflicts(), vec!["nodejs", "lua"]);
+    assert_eq!(result.to_ast(), None);
+    assert_eq!(
+        result.write(),
+        "# toolchain\n<<<<<<< ours\nnodejs 20.9 system\n=======\nnodejs 21.1 system\n>>>>>>> theirs\nruby  3.1 # main\n<<<<<<< ours\n=======\nlua 5.4\n>>>>>>> theirs\n"
+    );
+}
+
+#[test]
+fn it_works_as_git_merge_driver() {
+    let dir = TempDir::new("_tool-versions-merge-driver");
+
+    fs::write(dir.join("base"), BASE).unwrap();
+    fs::write(
+        dir.join("ours"),
+        "# toolchain\nnodejs 20.9 system\nruby  3.1 # main\nlua 5.3\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("theirs"),
+        "# toolchain\nnodejs 18.12 system\nruby  3.1 # main\nlua 5.4\n",
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_tool-versions-merge"))
+        .arg(dir.join("base"))
+        .arg(dir.join("ours"))
+        .arg(dir.join("theirs"))
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("ours")).unwrap(),
+        "# toolchain\nnodejs 20.9 system\nruby  3.1 # main\nlua 5.4\n"
+    );
+
+    fs::write(dir.join("theirs"), "# toolchain\nnodejs 16 system\n").unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_tool-versions-merge"))
+        .arg(dir.join("base"))
+        .arg(dir.join("ours"))
+        .arg(dir.join("theirs"))
+        .status()
+        .unwrap();
+
+    assert_eq!(status.code(), Some(1));
+}
@@ -137,3 +137,124 @@ fn it_works() {
         },
     );
 }
+
+#[test]
+fn it_parses_conflict_blocks() {
+    let input =
+        "<<<<<<< HEAD\nnodejs 20.9\n=======\nnodejs 21.1\n>>>>>>> feature\nnodejs 18\n=======\n";
+
+    let ast = parser::parse(input);
+
+    assert_eq!(
+        ast,
+        AST {
+            lines: vec![
+                Line::Conflict {
+                    error: SyntaxError::ConflictMarker,
+                    ours_marker: Unparsed::new("<<<<<<< HEAD".to_string()),
+                    ours: vec![Line::ToolDefinition {
+                        name: Identifier::new("nodejs".to_string()),
+                        versions: Versions::new(vec![(
+                            Whitespace::new(" ".to_string()),
+                            Version::new("20.9".to_string()),
+                        )]),
+                        whitespace: None,
+                        comment: None,
+                    }],
+                    base_marker: None,
+                    base: vec![],
+                    separator: Unparsed::new("=======".to_string()),
+                    theirs: vec![Line::ToolDefinition {
+                        name: Identifier::new("nodejs".to_string()),
+                        versions: Versions::new(vec![(
+                            Whitespace::new(" ".to_string()),
+                            Version::new("21.1".to_string()),
+                        )]),
+                        whitespace: None,
+                        comment: None,
+                    }],
+                    theirs_marker: Unparsed::new(">>>>>>> feature".to_string()),
+                },
+                Line::Invalid {
+                    error: SyntaxError::DuplicateIdentifier(Identifier::new("nodejs".to_string())),
+                    unparsed: Unparsed::new("nodejs 18".to_string()),
+                },
+                Line::Invalid {
+                    error: SyntaxError::ConflictMarker,
+                    unparsed: Unparsed::new("=======".to_string()),
+                },
+            ]
+        }
+    );
+
+    assert_eq!(
+        ast.numbered_lines()
+            .iter()
+            .map(|(number, _)| *number)
+            .collect::<Vec<usize>>(),
+        vec![1, 6, 7]
+    );
+}
+
+#[test]
+fn it_parses_diff3_conflict_blocks() {
+    let input = "<<<<<<< ours\nruby 3.2\n||||||| base\nruby 3.1\n=======\n>>>>>>> theirs\n";
+
+    match &parser::parse(input).lines[..] {
+        [Line::Conflict {
+            ours,
+            base_marker,
+            base,
+            theirs,
+            ..
+        }] => {
+            assert_eq!(ours.len(), 1);
+            assert_eq!(
+                base_marker,
+                &Some(Unparsed::new("||||||| base".to_string()))
+            );
+            assert_eq!(base.len(), 1);
+            assert_eq!(theirs.len(), 0);
+        }
+        lines => panic!("unexpected lines {:?}", lines),
+    }
+}
+
+#[test]
+fn it_validates_nodes() {
+    assert_eq!(
+        Identifier::try_new("nodejs-lts_1.x"),
+        Ok(Identifier::new("nodejs-lts_1.x".to_string()))
+    );
+    assert_eq!(
+        Identifier::try_new("my tool"),
+        Err(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Identifier",
+        })
+    );
+    assert_eq!(
+        Identifier::try_new(""),
+        Err(SyntaxError::UnexpectedEOL {
+            expected: "Identifier",
+        })
+    );
+    assert_eq!(
+        Version::try_new("ref:v1.2!"),
+        Ok(Version::new("ref:v1.2!".to_string()))
+    );
+    assert_eq!(
+        Version::try_new("1#2"),
+        Err(SyntaxError::UnexpectedToken {
+            token: '#',
+            expected: "Version",
+        })
+    );
+    assert_eq!(
+        Version::try_new("1\n2"),
+        Err(SyntaxError::UnexpectedToken {
+            token: '\n',
+            expected: "Version",
+        })
+    );
+}
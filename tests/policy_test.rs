@@ -0,0 +1,79 @@
+use tool_versions::{
+    policy::{Constraint, Operator, Policy, Rule, Violation},
+    ToolVersions,
+};
+
+const POLICY: &str = "# security policy\nrange nodejs >=18 <23\nban ruby 2.*\nrequire terraform\nforbid python2\nforbid-system nodejs\nforbid-latest\n";
+
+#[test]
+fn it_parses_policy() {
+    let policy = Policy::parse(POLICY).unwrap();
+
+    assert_eq!(
+        policy.rules[0],
+        Rule::Range {
+            tool_name: "nodejs".to_string(),
+            constraints: vec![
+                Constraint {
+                    operator: Operator::GreaterOrEqual,
+                    version: "18".to_string(),
+                },
+                Constraint {
+                    operator: Operator::Less,
+                    version: "23".to_string(),
+                },
+            ],
+        }
+    );
+    assert_eq!(policy.rules.len(), 6);
+
+    let error = Policy::parse("require nodejs\nallow everything\n").unwrap_err();
+
+    assert_eq!(error.line, 2);
+    assert_eq!(error.to_string(), "line 2: invalid rule 'allow'");
+}
+
+#[test]
+fn it_evaluates_policy() {
+    let policy = Policy::parse(POLICY).unwrap();
+    let tools = ToolVersions::from(
+        "# pins\nnodejs 16.20 20.9 system\nruby 2.7.8\npython2 2.7\nlua latest:5\n",
+    );
+
+    let violations: Vec<(String, Option<usize>)> = policy
+        .evaluate(&tools)
+        .into_iter()
+        .map(|violation| (violation.message, violation.line))
+        .collect();
+
+    assert_eq!(
+        violations,
+        vec![
+            ("nodejs 16.20 does not satisfy >=18".to_string(), Some(2)),
+            ("ruby 2.7.8 is banned (2.*)".to_string(), Some(3)),
+            ("terraform must be pinned".to_string(), None),
+            ("python2 is forbidden".to_string(), Some(4)),
+            ("nodejs must not use system".to_string(), Some(2)),
+            ("lua must not use latest:5".to_string(), Some(5)),
+        ]
+    );
+}
+
+#[test]
+fn it_passes_compliant_files() {
+    let policy = Policy::parse(POLICY).unwrap();
+    let tools = ToolVersions::from("nodejs 20.9\nruby 3.2\nterraform 1.5.0\n");
+
+    assert_eq!(policy.evaluate(&tools), Vec::<Violation>::new());
+}
+
+#[test]
+fn it_orders_package_revisions_after_releases() {
+    let constraint = Constraint {
+        operator: Operator::Greater,
+        version: "3.2.2".to_string(),
+    };
+
+    assert!(constraint.matches("3.2.2-1"));
+    assert!(!constraint.matches("3.2.2"));
+}
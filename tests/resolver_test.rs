@@ -0,0 +1,68 @@
+use tool_versions::{
+    inventory::{Installs, Status},
+    resolver::{self, Resolution, Skipped},
+    ToolVersions,
+};
+
+struct FakeInstalls;
+
+impl Installs for FakeInstalls {
+    fn status(&self, tool_name: &str, version: &str) -> Status {
+        match (tool_name, version) {
+            (_, "system") => Status::System,
+            ("lua", _) => Status::PluginMissing,
+            ("nodejs", "20.9") | ("ruby", "3.1") => Status::Installed,
+            _ => Status::NotInstalled,
+        }
+    }
+}
+
+#[test]
+fn it_selects_first_installed_version() {
+    let tools = ToolVersions::from("nodejs 18.12 20.9 system\nruby 3.2 3.1\nlua 5.4 system\n");
+
+    assert_eq!(
+        resolver::resolve(&tools, &FakeInstalls),
+        vec![
+            Resolution {
+                tool_name: "nodejs".to_string(),
+                selected: Some(("20.9".to_string(), Status::Installed)),
+                skipped: vec![Skipped {
+                    version: "18.12".to_string(),
+                    reason: Status::NotInstalled,
+                }],
+            },
+            Resolution {
+                tool_name: "ruby".to_string(),
+                selected: Some(("3.1".to_string(), Status::Installed)),
+                skipped: vec![Skipped {
+                    version: "3.2".to_string(),
+                    reason: Status::NotInstalled,
+                }],
+            },
+            Resolution {
+                tool_name: "lua".to_string(),
+                selected: Some(("system".to_string(), Status::System)),
+                skipped: vec![Skipped {
+                    version: "5.4".to_string(),
+                    reason: Status::PluginMissing,
+                }],
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_reports_unresolvable_tools() {
+    let tools = ToolVersions::from("golang 1.21 1.22\n");
+
+    let resolution = resolver::resolve_tool(&tools, "golang", &FakeInstalls).unwrap();
+
+    assert_eq!(resolution.version(), None);
+    assert_eq!(resolution.skipped.len(), 2);
+
+    assert_eq!(
+        resolver::resolve_tool(&tools, "nodejs", &FakeInstalls),
+        None
+    );
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::TempDir;
+use std::{fs, path::PathBuf};
+use tool_versions::{
+    ast::SyntaxError,
+    scanner::{self, Options, ScanError},
+};
+
+fn monorepo(name: &str) -> TempDir {
+    let root = TempDir::new(name);
+
+    for dir in [
+        ".git",
+        "node_modules/pkg",
+        "packages/api",
+        "packages/web/build",
+        "packages/web/src/deep",
+    ] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+    }
+
+    let files = [
+        (".tool-versions", "nodejs 20.9\n"),
+        (".git/.tool-versions", "nodejs 1\n"),
+        ("node_modules/pkg/.tool-versions", "nodejs 2\n"),
+        ("packages/api/.tool-versions", "nodejs 18.12\n+broken\n"),
+        ("packages/web/.tool-versions", "nodejs 20.9\nruby 3.2\n"),
+        ("packages/web/build/.tool-versions", "nodejs 3\n"),
+        ("packages/web/src/deep/.tool-versions", "nodejs 4\n"),
+        (".gitignore", "node_modules/\n"),
+        ("packages/web/.gitignore", "/build\n"),
+    ];
+
+    for (path, content) in files {
+        fs::write(root.join(path), content).unwrap();
+    }
+
+    root
+}
+
+#[test]
+fn it_scans_tree() {
+    let root = monorepo("_tool-versions-scanner");
+
+    let scan = scanner::scan(&root, &Options::default());
+
+    assert_eq!(
+        scan.files.keys().cloned().collect::<Vec<PathBuf>>(),
+        vec![
+            root.join(".tool-versions"),
+            root.join("packages/api/.tool-versions"),
+            root.join("packages/web/.tool-versions"),
+            root.join("packages/web/src/deep/.tool-versions"),
+        ]
+    );
+
+    assert_eq!(
+        scan.files[&root.join("packages/web/.tool-versions")].versions("ruby"),
+        Some(vec!["3.2".to_string()])
+    );
+
+    match &scan.errors[..] {
+        [ScanError::Syntax { path, line, error }] => {
+            assert_eq!(path, &root.join("packages/api/.tool-versions"));
+            assert_eq!(*line, 2);
+            assert_eq!(
+                error,
+                &SyntaxError::UnexpectedToken {
+                    token: '+',
+                    expected: "Identifier,Whitespace,Comment",
+                }
+            );
+        }
+        errors => panic!("unexpected errors {:?}", errors),
+    }
+}
+
+#[test]
+fn it_respects_depth_and_patterns() {
+    let root = monorepo("_tool-versions-scanner-options");
+
+    let scan = scanner::scan(
+        &root,
+        &Options {
+            max_depth: Some(2),
+            ignore: vec![".git/".to_string(), "packages/a*".to_string()],
+            read_gitignore: false,
+            threads: 2,
+            ..Options::default()
+        },
+    );
+
+    assert_eq!(
+        scan.files.keys().cloned().collect::<Vec<PathBuf>>(),
+        vec![
+            root.join(".tool-versions"),
+            root.join("node_modules/pkg/.tool-versions"),
+            root.join("packages/web/.tool-versions"),
+        ]
+    );
+    assert!(scan.errors.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn it_does_not_follow_symlinked_directories() {
+    let root = monorepo("_tool-versions-scanner-symlinks");
+
+    std::os::unix::fs::symlink(&*root, root.join("packages/api/self")).unwrap();
+
+    let scan = scanner::scan(&root, &Options::default());
+
+    assert_eq!(
+        scan.files.keys().cloned().collect::<Vec<PathBuf>>(),
+        vec![
+            root.join(".tool-versions"),
+            root.join("packages/api/.tool-versions"),
+            root.join("packages/web/.tool-versions"),
+            root.join("packages/web/src/deep/.tool-versions"),
+        ]
+    );
+}
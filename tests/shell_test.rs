@@ -0,0 +1,49 @@
+use tool_versions::{
+    shell::{self, Shell},
+    ToolVersions,
+};
+
+#[test]
+fn it_exports_for_each_shell() {
+    let tools = ToolVersions::from("nodejs 18.12 system\nfoo-bar path:/opt/it's $HOME\n");
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Bash),
+        "export ASDF_NODEJS_VERSION='18.12 system'\nexport ASDF_FOO_BAR_VERSION='path:/opt/it'\\''s $HOME'\n"
+    );
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Zsh),
+        shell::exports(&tools, Shell::Posix)
+    );
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Fish),
+        "set -gx ASDF_NODEJS_VERSION '18.12 system'\nset -gx ASDF_FOO_BAR_VERSION 'path:/opt/it\\'s $HOME'\n"
+    );
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Dotenv),
+        "ASDF_NODEJS_VERSION=\"18.12 system\"\nASDF_FOO_BAR_VERSION=\"path:/opt/it's \\$HOME\"\n"
+    );
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Makefile),
+        "export ASDF_NODEJS_VERSION := 18.12 system\nexport ASDF_FOO_BAR_VERSION := path:/opt/it's $$HOME\n"
+    );
+}
+
+#[test]
+fn it_does_not_quote_simple_values() {
+    let tools = ToolVersions::from("nodejs 18.12\n");
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Posix),
+        "export ASDF_NODEJS_VERSION=18.12\n"
+    );
+
+    assert_eq!(
+        shell::exports(&tools, Shell::Fish),
+        "set -gx ASDF_NODEJS_VERSION 18.12\n"
+    );
+}
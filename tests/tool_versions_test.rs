@@ -56,9 +56,9 @@ fn it_works_with_file() {
         ]
     );
 
-    tools.set_versions("nodejs", vec!["8", "9", "10"]);
-    tools.set_versions("ruby", vec!["13"]);
-    tools.set_versions("lua", vec![]);
+    tools.set_versions("nodejs", vec!["8", "9", "10"]).unwrap();
+    tools.set_versions("ruby", vec!["13"]).unwrap();
+    tools.set_versions("lua", vec![]).unwrap();
 
     assert_eq!(
         tools.versions("nodejs"),
@@ -89,7 +89,7 @@ fn it_works_with_string() {
         Some(vec!["18.12".to_string(), "system".to_string()])
     );
 
-    tools.set_versions("nodejs", vec!["8", "9", "10"]);
+    tools.set_versions("nodejs", vec!["8", "9", "10"]).unwrap();
 
     assert_eq!(
         tools.versions("nodejs"),
@@ -109,14 +109,127 @@ fn it_works_with_new() {
 
     assert_eq!(tools.errors(), Vec::<&ast::SyntaxError>::new());
 
-    tools.set_versions("lua", vec![]);
+    tools.set_versions("lua", vec![]).unwrap();
 
     assert_eq!(tools.versions("lua"), None);
 
-    tools.set_versions("ruby", vec!["12", "19"]);
+    tools.set_versions("ruby", vec!["12", "19"]).unwrap();
 
     assert_eq!(
         tools.versions("ruby"),
         Some(vec!["12".to_string(), "19".to_string()])
     );
 }
+
+#[test]
+fn it_resolves_conflicts() {
+    let mut tools = ToolVersions::from(
+        "<<<<<<< HEAD\nnodejs 20.9\n=======\nnodejs 18.12\n>>>>>>> feature\nruby 3.2\n",
+    );
+
+    assert_eq!(tools.versions("nodejs"), None);
+    assert_eq!(tools.errors(), vec![&ast::SyntaxError::ConflictMarker]);
+    assert_eq!(tools.line_number("ruby"), Some(6));
+
+    tools.resolve_conflicts(tool_versions::transformer::ConflictResolution::HighestVersion);
+
+    assert_eq!(tools.versions("nodejs"), Some(vec!["20.9".to_string()]));
+    assert_eq!(tools.errors(), Vec::<&ast::SyntaxError>::new());
+    assert_eq!(tools.write(), "nodejs 20.9\nruby 3.2\n");
+}
+
+#[test]
+fn it_edits_single_versions() {
+    let mut tools = ToolVersions::from("nodejs 18.12 system\n");
+
+    tools.append_version("nodejs", "20.9").unwrap();
+    tools.prepend_version("nodejs", "21.6").unwrap();
+    tools.append_version("nodejs", "18.12").unwrap();
+    tools.promote_version("nodejs", "20.9").unwrap();
+    tools.remove_version("nodejs", "system").unwrap();
+    tools.dedupe_versions("nodejs").unwrap();
+    tools.promote_version("nodejs", "16.20").unwrap();
+    tools.promote_version("deno", "1.40").unwrap();
+
+    assert_eq!(
+        tools.versions("nodejs"),
+        Some(vec![
+            "20.9".to_string(),
+            "21.6".to_string(),
+            "18.12".to_string()
+        ])
+    );
+    assert_eq!(tools.versions("deno"), None);
+}
+
+#[test]
+fn it_rejects_invalid_input() {
+    let mut tools = ToolVersions::from("nodejs 18.12\n");
+
+    assert_eq!(
+        tools.set_versions("my tool", vec!["1"]),
+        Err(ast::SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Identifier",
+        })
+    );
+    assert_eq!(
+        tools.set_versions("nodejs", vec!["1 # 2"]),
+        Err(ast::SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Version",
+        })
+    );
+    assert_eq!(
+        tools.append_version("nodejs", ""),
+        Err(ast::SyntaxError::UnexpectedEOL {
+            expected: "Version",
+        })
+    );
+    assert_eq!(tools.write(), "nodejs 18.12\n");
+}
+
+#[test]
+fn it_writes_output_that_reparses_identically() {
+    let mut tools = ToolVersions::from("# tools\nnodejs  18.12 # lts\n\nruby 3.2   \n");
+
+    tools
+        .set_versions("nodejs", vec!["20.9", "system"])
+        .unwrap();
+    tools.append_version("lua", "5.4").unwrap();
+    tools.prepend_version("ruby", "path:/opt/ruby").unwrap();
+    tools.remove_version("ruby", "3.2").unwrap();
+
+    let reparsed = ToolVersions::from(&tools.write());
+
+    assert_eq!(reparsed.write(), tools.write());
+    assert_eq!(
+        reparsed.versions("ruby"),
+        Some(vec!["path:/opt/ruby".to_string()])
+    );
+
+    for tool_name in ["nodejs", "ruby", "lua"] {
+        tools.set_versions(tool_name, vec![]).unwrap();
+    }
+
+    assert_eq!(tools.write(), "# tools\n\n");
+    assert_eq!(ToolVersions::from(&tools.write()).write(), tools.write());
+
+    let mut tools =
+        ToolVersions::from("<<<<<<< ours\nnodejs 20.9\n=======\nnodejs 21.1\n>>>>>>> theirs\n");
+
+    assert_eq!(
+        tools.set_versions("nodejs", vec!["22"]),
+        Err(ast::SyntaxError::ConflictMarker)
+    );
+    assert_eq!(
+        tools.append_version("nodejs", "22"),
+        Err(ast::SyntaxError::ConflictMarker)
+    );
+    tools.set_versions("ruby", vec!["3.2"]).unwrap();
+
+    let reparsed = ToolVersions::from(&tools.write());
+
+    assert_eq!(reparsed.write(), tools.write());
+    assert_eq!(reparsed.versions("ruby"), Some(vec!["3.2".to_string()]));
+}
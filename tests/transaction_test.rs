@@ -0,0 +1,142 @@
+use tool_versions::{
+    ast::SyntaxError,
+    transaction::{Operation, Reason, Transaction, TransactionError},
+    ToolVersions,
+};
+
+#[test]
+fn it_applies_operations_atomically() {
+    let mut tools = ToolVersions::from("# tools\nnodejs  18.12 # old\ngolang 1.21\nlua 5.4\n");
+
+    tools
+        .transaction()
+        .set("nodejs", vec!["20.9", "system"])
+        .rename("golang", "go")
+        .set("go", vec!["1.22"])
+        .remove("lua")
+        .comment("nodejs", Some("lts"))
+        .comment("go", Some("toolchain"))
+        .set("ruby", vec!["3.3"])
+        .commit()
+        .unwrap();
+
+    assert_eq!(
+        tools.write(),
+        "# tools\nnodejs  20.9 system # lts\ngo 1.22 # toolchain\nruby 3.3\n"
+    );
+
+    tools
+        .transaction()
+        .comment("nodejs", None)
+        .commit()
+        .unwrap();
+
+    assert_eq!(
+        tools.write(),
+        "# tools\nnodejs  20.9 system\ngo 1.22 # toolchain\nruby 3.3\n"
+    );
+}
+
+#[test]
+fn it_rolls_back_on_failure() {
+    let source = "nodejs 18.12\nruby 3.2\n";
+    let mut tools = ToolVersions::from(source);
+
+    let error = tools
+        .transaction()
+        .set("nodejs", vec!["20.9"])
+        .rename("nodejs", "ruby")
+        .commit()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        TransactionError {
+            index: 1,
+            operation: Operation::Rename {
+                from: "nodejs".to_string(),
+                to: "ruby".to_string(),
+            },
+            reason: Reason::AlreadyExists("ruby".to_string()),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "operation 2 (rename nodejs to ruby) failed: ruby is already pinned"
+    );
+    assert_eq!(tools.write(), source);
+}
+
+#[test]
+fn it_discards_every_queued_operation_on_failure() {
+    let source = "nodejs 18.12 # lts\nruby 3.2\n";
+    let mut tools = ToolVersions::from(source);
+
+    let error = tools
+        .transaction()
+        .remove("ruby")
+        .comment("nodejs", Some("current"))
+        .set("nodejs", vec![])
+        .commit()
+        .unwrap_err();
+
+    assert_eq!(error.index, 2);
+    assert_eq!(error.reason, Reason::EmptyVersions);
+    assert_eq!(error.operation.to_string(), "set nodejs");
+    assert_eq!(tools.write(), source);
+}
+
+fn reason(tools: &mut ToolVersions, build: fn(Transaction) -> Transaction) -> Reason {
+    build(tools.transaction()).commit().unwrap_err().reason
+}
+
+#[test]
+fn it_validates_operations() {
+    let mut tools = ToolVersions::from("nodejs 18.12\n");
+
+    assert_eq!(
+        reason(&mut tools, |t| t.set("nodejs", vec![])),
+        Reason::EmptyVersions
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t.set("nodejs", vec!["1 # 2"])),
+        Reason::Syntax(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Version",
+        })
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t.rename("nodejs", "node js")),
+        Reason::Syntax(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Identifier",
+        })
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t.remove("ruby")),
+        Reason::NotFound("ruby".to_string())
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t
+            .remove("nodejs")
+            .comment("nodejs", Some("gone"))),
+        Reason::NotFound("nodejs".to_string())
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t.comment("nodejs", Some("a\nb"))),
+        Reason::MultilineComment
+    );
+    assert_eq!(tools.write(), "nodejs 18.12\n");
+
+    let mut tools =
+        ToolVersions::from("<<<<<<< ours\nruby 3.2\n=======\n>>>>>>> theirs\nnodejs 18.12\n");
+
+    assert_eq!(
+        reason(&mut tools, |t| t.set("ruby", vec!["3.3"])),
+        Reason::Conflicted("ruby".to_string())
+    );
+    assert_eq!(
+        reason(&mut tools, |t| t.rename("nodejs", "ruby")),
+        Reason::Conflicted("ruby".to_string())
+    );
+}
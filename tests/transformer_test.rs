@@ -2,7 +2,7 @@ use std::path::Path;
 use tool_versions::ast::{
     Identifier, Line, Node, SyntaxError, Unparsed, Version, Versions, Whitespace, AST,
 };
-use tool_versions::{parser, transformer};
+use tool_versions::{parser, transformer, writer};
 
 #[test]
 fn it_sets_more_versions() {
@@ -16,7 +16,8 @@ fn it_sets_more_versions() {
             Version::new("9".to_string()),
             Version::new("10".to_string()),
         ],
-    );
+    )
+    .unwrap();
 
     assert_eq!(
         result,
@@ -153,7 +154,8 @@ fn it_sets_less_versions() {
         &ast,
         Identifier::new("ruby".to_string()),
         vec![Version::new("14".to_string())],
-    );
+    )
+    .unwrap();
 
     assert_eq!(
         result,
@@ -280,7 +282,8 @@ fn it_sets_new_versions() {
         &ast,
         Identifier::new("golang".to_string()),
         vec![Version::new("1337".to_string())],
-    );
+    )
+    .unwrap();
 
     assert_eq!(
         result,
@@ -422,7 +425,8 @@ fn it_sets_new_versions_on_empty() {
         &ast,
         Identifier::new("golang".to_string()),
         vec![Version::new("1337".to_string())],
-    );
+    )
+    .unwrap();
 
     assert_eq!(
         result,
@@ -444,7 +448,8 @@ fn it_sets_new_versions_on_empty() {
 fn it_sets_new_empty_versions() {
     let ast = parser::parse_file(Path::new("tests/__fixtures__/_tool-versions")).unwrap();
 
-    let result = transformer::set_versions(&ast, Identifier::new("golang".to_string()), vec![]);
+    let result =
+        transformer::set_versions(&ast, Identifier::new("golang".to_string()), vec![]).unwrap();
 
     assert_eq!(result, ast);
 }
@@ -453,7 +458,8 @@ fn it_sets_new_empty_versions() {
 fn it_removes_versions() {
     let ast = parser::parse_file(Path::new("tests/__fixtures__/_tool-versions")).unwrap();
 
-    let result = transformer::set_versions(&ast, Identifier::new("ruby".to_string()), vec![]);
+    let result =
+        transformer::set_versions(&ast, Identifier::new("ruby".to_string()), vec![]).unwrap();
 
     assert_eq!(
         result,
@@ -562,3 +568,154 @@ fn it_removes_versions() {
         },
     );
 }
+
+#[test]
+fn it_resolves_conflicts() {
+    let ast = parser::parse(
+        "# pins\n<<<<<<< HEAD\nnodejs 20.9 # lts\nruby 3.2\n=======\nnodejs 21.1\nruby 3.1\nlua 5.4\n>>>>>>> feature\n",
+    );
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::Ours
+        )),
+        "# pins\nnodejs 20.9 # lts\nruby 3.2\n"
+    );
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::Theirs
+        )),
+        "# pins\nnodejs 21.1\nruby 3.1\nlua 5.4\n"
+    );
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::HighestVersion
+        )),
+        "# pins\nnodejs 21.1\nruby 3.2\nlua 5.4\n"
+    );
+}
+
+#[test]
+fn it_keeps_comments_from_both_sides_when_resolving_highest_versions() {
+    let ast = parser::parse(
+        "<<<<<<< HEAD\n# ours\nnodejs 20.9\nruby 3.1 # main\n=======\n# theirs\n\nnodejs 21.1 # current\nruby 3.2\n>>>>>>> feature\n",
+    );
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::HighestVersion
+        )),
+        "# ours\n# theirs\n\nnodejs 21.1 # current\nruby 3.2\n"
+    );
+
+    let ast = parser::parse(
+        "<<<<<<< HEAD\nnodejs 21.1 # ours\n\nruby 3.1\n=======\nnodejs 20.9 # theirs\nruby 3.1\n# end\n>>>>>>> feature\n",
+    );
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::HighestVersion
+        )),
+        "nodejs 21.1 # ours\n\nruby 3.1\n# end\n"
+    );
+
+    let ast = parser::parse("<<<<<<< HEAD\njava 17.0.2\n=======\njava 17.0.2-8\n>>>>>>> feature\n");
+
+    assert_eq!(
+        writer::write(&transformer::resolve_conflicts(
+            &ast,
+            transformer::ConflictResolution::HighestVersion
+        )),
+        "java 17.0.2-8\n"
+    );
+}
+
+#[test]
+fn it_edits_single_versions() {
+    let ast = parser::parse("nodejs  18.12    system  # foobar\nruby 3.2\n");
+    let nodejs = || Identifier::new("nodejs".to_string());
+    let version = |version: &str| Version::new(version.to_string());
+
+    assert_eq!(
+        writer::write(&transformer::append_version(&ast, nodejs(), version("20.9")).unwrap()),
+        "nodejs  18.12    system 20.9  # foobar\nruby 3.2\n"
+    );
+    assert_eq!(
+        writer::write(&transformer::prepend_version(&ast, nodejs(), version("20.9")).unwrap()),
+        "nodejs 20.9  18.12    system  # foobar\nruby 3.2\n"
+    );
+    assert_eq!(
+        writer::write(&transformer::remove_version(&ast, nodejs(), version("18.12")).unwrap()),
+        "nodejs    system  # foobar\nruby 3.2\n"
+    );
+    assert_eq!(
+        writer::write(&transformer::promote_version(&ast, nodejs(), version("system")).unwrap()),
+        "nodejs    system  18.12  # foobar\nruby 3.2\n"
+    );
+    assert_eq!(
+        writer::write(
+            &transformer::append_version(&ast, Identifier::new("lua".to_string()), version("5.4"))
+                .unwrap()
+        ),
+        "nodejs  18.12    system  # foobar\nruby 3.2\nlua 5.4\n"
+    );
+    assert_eq!(
+        writer::write(
+            &transformer::remove_version(&ast, Identifier::new("ruby".to_string()), version("3.2"))
+                .unwrap()
+        ),
+        "nodejs  18.12    system  # foobar\n"
+    );
+
+    let ast = parser::parse("nodejs 18.12  system 18.12 20.9 system\n");
+
+    assert_eq!(
+        writer::write(&transformer::dedupe_versions(&ast, nodejs()).unwrap()),
+        "nodejs 18.12  system 20.9\n"
+    );
+}
+
+#[test]
+fn it_produces_asts_that_reparse_identically() {
+    let ast = parser::parse_file(Path::new("tests/__fixtures__/_tool-versions")).unwrap();
+    let version = |version: &str| Version::try_new(version).unwrap();
+
+    let ast = transformer::set_versions(
+        &ast,
+        Identifier::try_new("nodejs").unwrap(),
+        vec![version("20.9"), version("system"), version("18.12")],
+    )
+    .unwrap();
+    let ast =
+        transformer::remove_version(&ast, Identifier::try_new("ruby").unwrap(), version("2.7"))
+            .unwrap();
+    let ast =
+        transformer::append_version(&ast, Identifier::try_new("lua").unwrap(), version("5.4"))
+            .unwrap();
+    let ast = transformer::promote_version(
+        &ast,
+        Identifier::try_new("nodejs").unwrap(),
+        version("18.12"),
+    )
+    .unwrap();
+
+    assert_eq!(parser::parse(&writer::write(&ast)), ast);
+    assert_eq!(
+        transformer::set_versions(
+            &ast,
+            Identifier::new("lua".to_string()),
+            vec![Version::new("5.4 # 5.1".to_string())]
+        ),
+        Err(SyntaxError::UnexpectedToken {
+            token: ' ',
+            expected: "Version",
+        })
+    );
+}
@@ -0,0 +1,88 @@
+use tool_versions::{
+    upgrade::{self, FileIndex, Strategy, Upgrade, VersionIndex},
+    ToolVersions,
+};
+
+fn upgrade(tool_name: &str, line: usize, from: &str, to: &str) -> Upgrade {
+    Upgrade {
+        tool_name: tool_name.to_string(),
+        line: Some(line),
+        from: from.to_string(),
+        to: to.to_string(),
+    }
+}
+
+#[test]
+fn it_reads_file_index() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+
+    assert_eq!(
+        index.available_versions("python").unwrap(),
+        vec!["3.11.4", "3.11.7", "3.12.0", "3.12.1", "3.13.0a1"]
+    );
+    assert!(index.available_versions("ruby").unwrap().is_empty());
+}
+
+#[test]
+fn it_plans_upgrades_by_strategy() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let tools = ToolVersions::from("nodejs 18.12.0 system\npython 3.11.4\nruby 3.2.0\n");
+
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Patch).unwrap(),
+        vec![
+            upgrade("nodejs", 1, "18.12.0", "18.12.1"),
+            upgrade("python", 2, "3.11.4", "3.11.7"),
+        ]
+    );
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Minor).unwrap(),
+        vec![
+            upgrade("nodejs", 1, "18.12.0", "18.19.0"),
+            upgrade("python", 2, "3.11.4", "3.12.1"),
+        ]
+    );
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Major).unwrap(),
+        vec![
+            upgrade("nodejs", 1, "18.12.0", "21.6.1"),
+            upgrade("python", 2, "3.11.4", "3.12.1"),
+        ]
+    );
+}
+
+#[test]
+fn it_keeps_the_precision_of_floating_pins() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let tools = ToolVersions::from("nodejs 18\npython 3.11\n");
+
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Patch).unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Minor).unwrap(),
+        vec![upgrade("python", 2, "3.11", "3.12")]
+    );
+    assert_eq!(
+        upgrade::plan(&tools, &index, Strategy::Major).unwrap(),
+        vec![
+            upgrade("nodejs", 1, "18", "21"),
+            upgrade("python", 2, "3.11", "3.12"),
+        ]
+    );
+}
+
+#[test]
+fn it_applies_upgrades_keeping_layout() {
+    let index = FileIndex::new("tests/__fixtures__/index");
+    let mut tools = ToolVersions::from("# pins\nnodejs   18.12.0 system # lts\npython 3.12.1\n");
+
+    let upgrades = upgrade::plan(&tools, &index, Strategy::Minor).unwrap();
+    upgrade::apply(&mut tools, &upgrades).unwrap();
+
+    assert_eq!(
+        tools.write(),
+        "# pins\nnodejs   18.19.0 system # lts\npython 3.12.1\n"
+    );
+}
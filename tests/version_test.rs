@@ -0,0 +1,32 @@
+use std::cmp::Ordering;
+use tool_versions::version;
+
+#[test]
+fn it_compares_versions() {
+    assert_eq!(version::compare("18.12.1", "18.9.0"), Ordering::Greater);
+    assert_eq!(version::compare("v20", "20"), Ordering::Equal);
+    assert_eq!(version::compare("1.0.0-rc1", "1.0.0"), Ordering::Less);
+    assert_eq!(version::compare("1.0.1", "1.0"), Ordering::Greater);
+    assert_eq!(version::compare("3.11.0b1", "3.11.0"), Ordering::Less);
+    assert_eq!(
+        version::compare("temurin-17", "temurin-11"),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn it_orders_semver_pre_releases_before_releases() {
+    assert_eq!(
+        version::compare_semver("1.21.0-0", "1.21.0"),
+        Ordering::Less
+    );
+    assert_eq!(
+        version::compare_semver("1.21.0-rc.2", "1.21.0-rc.10"),
+        Ordering::Less
+    );
+    assert_eq!(
+        version::compare_semver("1.21.0-rc.2", "1.20.9"),
+        Ordering::Greater
+    );
+    assert_eq!(version::compare("1.21.0-0", "1.21.0"), Ordering::Greater);
+}
@@ -135,3 +135,11 @@ fn it_works() {
 
     assert_eq!(result, expected)
 }
+
+#[test]
+fn it_writes_empty_ast() {
+    let ast = AST { lines: vec![] };
+
+    assert_eq!(writer::write(&ast), "");
+    assert_eq!(tool_versions::parser::parse(&writer::write(&ast)), ast);
+}